@@ -1,14 +1,58 @@
-use clap::{Parser, Subcommand};
+use crate::{
+    display::OutputFormat,
+    stats::{FileSort, GroupBy, Interval, SampleBy},
+};
+use clap::{builder::PossibleValue, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "git-team-stats")]
 #[command(about = "Team Git statistics and analysis tool", long_about = None)]
 #[command(version)]
+// Each bool is an independent command-line flag
+#[allow(clippy::struct_excessive_bools)]
 pub struct Cli {
     /// Path to the Git repository (defaults to current directory)
-    #[arg(short, long, default_value = ".")]
-    pub repo: PathBuf,
+    #[arg(short, long)]
+    pub repo: Option<PathBuf>,
+
+    /// Leave commits larger than --outlier-lines out of all totals
+    #[arg(long, global = true)]
+    pub exclude_outliers: bool,
+
+    /// Lines changed above which a commit counts as an outlier
+    #[arg(long, global = true, default_value = "1000")]
+    pub outlier_lines: usize,
+
+    /// Don't count whitespace, line-ending or blank-line changes as changed lines
+    #[arg(long, global = true)]
+    pub ignore_whitespace: bool,
+
+    /// Leave commits that only change formatting out of all totals
+    #[arg(long, global = true)]
+    pub exclude_formatting: bool,
+
+    /// Minutes between commits that still count as one work session (overrides `SESSION_GAP_MINUTES`)
+    #[arg(long, global = true)]
+    pub session_gap: Option<i64>,
+
+    /// Minutes credited before each session's first commit (overrides `SESSION_LEAD_IN_MINUTES`)
+    #[arg(long, global = true)]
+    pub session_lead_in: Option<i64>,
+
+    /// Output format: text or markdown for every command, json for changelog and growth, html
+    /// for report (default: markdown for changelog, text otherwise)
+    #[arg(short, long, global = true, value_enum)]
+    pub format: Option<OutputFormat>,
+
+    /// Write the command's charts as SVG files into this directory (time-analysis, contributors,
+    /// author, report and growth)
+    #[arg(long, global = true, value_name = "DIR")]
+    pub chart_out: Option<PathBuf>,
+
+    /// Also write each chart as a PNG next to its SVG
+    #[arg(long, global = true, requires = "chart_out")]
+    pub chart_png: bool,
 
     #[command(subcommand)]
     pub command: Commands,
@@ -21,6 +65,12 @@ pub enum Commands {
         /// Number of days to analyze (0 = all time)
         #[arg(short, long, default_value = "0")]
         days: i64,
+        /// Roll contributions up by path: file, dir[:depth] or module
+        #[arg(short, long, default_value = "file")]
+        group_by: GroupBy,
+        /// Show each contributor's language mix instead of line totals
+        #[arg(short, long, conflicts_with = "group_by")]
+        languages: bool,
     },
     /// Show time-based commit analysis
     TimeAnalysis {
@@ -36,12 +86,24 @@ pub enum Commands {
         /// Number of days to analyze (0 = all time)
         #[arg(short, long, default_value = "0")]
         days: i64,
+        /// Roll file changes up by path: file, dir[:depth] or module
+        #[arg(short, long, default_value = "file")]
+        group_by: GroupBy,
+        /// Rank files by commit touches or by line churn
+        #[arg(short, long, value_enum, default_value_t = FileSort::Touches)]
+        sort: FileSort,
     },
     /// Generate a comprehensive report
     Report {
         /// Report period: weekly or monthly
         #[arg(short, long, default_value = "weekly")]
         period: String,
+        /// Write the report to this file instead of stdout (Markdown and HTML)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Number of files to list in Markdown and HTML reports
+        #[arg(short, long, default_value = "20")]
+        top: usize,
     },
     /// Show overall team summary
     Summary {
@@ -49,22 +111,329 @@ pub enum Commands {
         #[arg(short, long, default_value = "30")]
         days: i64,
     },
+    /// Classify commits by Conventional Commits type
+    Commits {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+        /// Also break the types (feat, fix, ...) down per contributor and per team
+        #[arg(long)]
+        by_type: bool,
+        /// Period size for the breakdown over time
+        #[arg(short, long, value_enum, default_value_t = Interval::Week)]
+        interval: Interval,
+    },
+    /// Generate a changelog between two revisions
+    Changelog {
+        /// Revision range, e.g. v1.2.0..v1.3.0 (a missing end means HEAD)
+        range: String,
+        /// Document template file with {{placeholder}} fields
+        #[arg(long)]
+        template: Option<PathBuf>,
+        /// Template for each scope within a type, with {{scope}}, {{type}} and {{entries}} fields
+        #[arg(long)]
+        scope_template: Option<String>,
+        /// Template for each commit line, e.g. "- {{description}} ({{hash}})"
+        #[arg(long)]
+        entry_template: Option<String>,
+    },
+    /// Show issue tracker references: commits per ticket and per contributor
+    Issues {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+        /// Number of tickets to show
+        #[arg(short, long, default_value = "20")]
+        top: usize,
+    },
+    /// Show reverts, fixups and code re-changed soon after it was written
+    Rework {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "90")]
+        days: i64,
+        /// Lines changed again within this many days of being written count as rework
+        #[arg(short, long, default_value = "21")]
+        window: i64,
+        /// Roll rework up by path: file, dir[:depth] or module
+        #[arg(short, long, default_value = "dir")]
+        group_by: GroupBy,
+        /// Bucket size for the timeline
+        #[arg(short, long, value_enum, default_value_t = Interval::Month)]
+        interval: Interval,
+    },
+    /// Show how much of the code added in a period survives 30/90/180 days and until HEAD
+    Survival {
+        /// Number of days of added code to follow (0 = all time)
+        #[arg(short, long, default_value = "365")]
+        days: i64,
+        /// Roll survival up by path: file, dir[:depth] or module
+        #[arg(short, long, default_value = "dir")]
+        group_by: GroupBy,
+        /// Bucket size for the periods lines were written in
+        #[arg(short, long, value_enum, default_value_t = Interval::Month)]
+        interval: Interval,
+    },
+    /// Show commit size distributions and outlier commits
+    Sizes {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+        /// Number of outlier commits to show
+        #[arg(short, long, default_value = "20")]
+        top: usize,
+    },
+    /// Show formatting-only commits and whitespace churn per contributor
+    Formatting {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+        /// Number of formatting-only commits to show
+        #[arg(short, long, default_value = "20")]
+        top: usize,
+    },
+    /// Show binary file changes, bytes added and the largest blobs introduced
+    Binaries {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+        /// Number of largest blobs to show
+        #[arg(short, long, default_value = "20")]
+        top: usize,
+        /// Bucket size for the timeline
+        #[arg(short, long, value_enum, default_value_t = Interval::Month)]
+        interval: Interval,
+    },
+    /// Show how files, lines of code and object size grew over time
+    Growth {
+        /// Commits to sample the tree at
+        #[arg(long, value_enum, default_value_t = SampleBy::Month)]
+        by: SampleBy,
+        /// Sample every N first-parent commits (with --by commits)
+        #[arg(short = 'n', long, default_value = "100")]
+        every: usize,
+        /// Branch or revision whose first-parent history is sampled
+        #[arg(short, long, default_value = "HEAD")]
+        branch: String,
+        /// Number of languages and directories to show
+        #[arg(short, long, default_value = "10")]
+        top: usize,
+    },
+    /// List branches with ahead/behind counts and stale branches by owner
+    Branches {
+        /// Branch to compare with (overrides `MAIN_BRANCH`)
+        #[arg(short, long)]
+        main: Option<String>,
+        /// Days without commits after which a branch is stale
+        #[arg(short, long, default_value = "30")]
+        stale_days: i64,
+    },
+    /// Show lifetime, size and lead time of branches merged into the main branch
+    Merges {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "90")]
+        days: i64,
+        /// Branch merges go into (overrides `MAIN_BRANCH`)
+        #[arg(short, long)]
+        main: Option<String>,
+        /// Bucket size for the breakdown over time
+        #[arg(short, long, value_enum, default_value_t = Interval::Month)]
+        interval: Interval,
+        /// Number of recent merges to show
+        #[arg(short, long, default_value = "20")]
+        top: usize,
+    },
+    /// Show pull requests from squash and merge commits: per contributor, sizes, throughput
+    Prs {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "90")]
+        days: i64,
+        /// Bucket size for throughput
+        #[arg(short, long, value_enum, default_value_t = Interval::Week)]
+        interval: Interval,
+        /// Number of recent pull requests to show
+        #[arg(short, long, default_value = "20")]
+        top: usize,
+    },
+    /// Show release cadence and DORA-style delivery metrics from release tags
+    Delivery {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "0")]
+        days: i64,
+        /// Glob for release tags (overrides `RELEASE_TAGS`)
+        #[arg(long)]
+        tags: Option<String>,
+        /// Bucket size for trends
+        #[arg(short, long, value_enum, default_value_t = Interval::Month)]
+        interval: Interval,
+        /// Number of recent releases to show
+        #[arg(short, long, default_value = "20")]
+        top: usize,
+    },
+    /// Show ramp-up curves of new contributors against the team's historical median
+    Onboarding {
+        /// Count contributors whose first commit is within this many days as new (0 = everyone)
+        #[arg(short, long, default_value = "180")]
+        days: i64,
+        /// Length of the ramp-up window, in weeks
+        #[arg(long, default_value = "12")]
+        weeks: usize,
+        /// Number of new contributors to show
+        #[arg(short, long, default_value = "10")]
+        top: usize,
+    },
+    /// Classify contributors as active, ramping, fading or departed
+    Roster {
+        /// Recent window in days (overrides `ROSTER_ACTIVE_DAYS`, default 30)
+        #[arg(long)]
+        active_days: Option<i64>,
+        /// Days since the first commit during which someone is ramping up
+        /// (overrides `ROSTER_RAMPING_DAYS`, default 90)
+        #[arg(long)]
+        ramping_days: Option<i64>,
+        /// Days without a commit after which someone has departed
+        /// (overrides `ROSTER_DEPARTED_DAYS`, default 90)
+        #[arg(long)]
+        departed_days: Option<i64>,
+        /// Lines at HEAD a departed contributor must own to be listed
+        #[arg(long, default_value = "100")]
+        min_lines: usize,
+        /// Bucket size for headcount
+        #[arg(short, long, value_enum, default_value_t = Interval::Month)]
+        interval: Interval,
+    },
+    /// Summarize who contributed what to a release, for thank-you notes
+    Release {
+        /// Release tag or revision
+        tag: String,
+        /// Previous release (default: the closest earlier tag matching `RELEASE_TAGS`)
+        #[arg(long)]
+        from: Option<String>,
+        /// Number of files and directories to show
+        #[arg(short, long, default_value = "10")]
+        top: usize,
+    },
+    /// Show churn and lines of code per language
+    Languages {
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+    },
+    /// Show a full profile of one contributor
+    Author {
+        /// Contributor name or email (resolved through .mailmap)
+        identity: String,
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "0")]
+        days: i64,
+        /// Number of top files, directories and languages to show
+        #[arg(short, long, default_value = "10")]
+        top: usize,
+    },
+    /// Rank who knows a file or directory best, weighting recent changes higher
+    Experts {
+        /// File or directory path, relative to the repository root
+        path: String,
+        /// Number of days to analyze (0 = all time)
+        #[arg(short, long, default_value = "0")]
+        days: i64,
+        /// Days after which a change counts half as much (greater than 0)
+        #[arg(long, default_value = "90", value_parser = parse_half_life)]
+        half_life: f64,
+        /// Number of people to show
+        #[arg(short, long, default_value = "10")]
+        top: usize,
+    },
+    /// Open an interactive dashboard in the terminal
+    Tui {
+        /// Number of days to analyze at start (0 = all time)
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+        /// Period size for the trends tab at start
+        #[arg(short, long, value_enum, default_value_t = Interval::Month)]
+        interval: Interval,
+    },
+}
+
+/// A half-life must be a positive number of days, or every score would be 0, NaN or inverted
+fn parse_half_life(value: &str) -> Result<f64, String> {
+    let days: f64 = value.parse().map_err(|err| format!("{err}"))?;
+    if days.is_finite() && days > 0.0 {
+        Ok(days)
+    } else {
+        Err(format!("must be a positive number of days, got {value}"))
+    }
 }
 
-impl Commands {
-    pub fn days(&self) -> i64 {
-        match self {
-            Commands::Contributors { days } => *days,
-            Commands::TimeAnalysis { days } => *days,
-            Commands::Files { days, .. } => *days,
-            Commands::Summary { days } => *days,
-            Commands::Report { period } => {
-                if period == "weekly" {
-                    7
-                } else {
-                    30
-                }
+// The stats enums stay free of clap; these impls expose them as command-line values
+
+impl ValueEnum for FileSort {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Touches, Self::Added, Self::Deleted, Self::Churn]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Touches => {
+                PossibleValue::new("touches").help("Number of commits touching the path")
             }
-        }
+            Self::Added => PossibleValue::new("added").help("Lines added"),
+            Self::Deleted => PossibleValue::new("deleted").help("Lines deleted"),
+            Self::Churn => PossibleValue::new("churn").help("Lines added plus lines deleted"),
+        })
+    }
+}
+
+impl ValueEnum for Interval {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Week, Self::Month]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Week => PossibleValue::new("week").help("ISO weeks, e.g. 2024-W07"),
+            Self::Month => PossibleValue::new("month").help("Calendar months, e.g. 2024-02"),
+        })
+    }
+}
+
+impl ValueEnum for SampleBy {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Tag, Self::Month, Self::Commits]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Tag => PossibleValue::new("tag").help("Every tag, in commit order"),
+            Self::Month => {
+                PossibleValue::new("month").help("The last first-parent commit of each month")
+            }
+            Self::Commits => PossibleValue::new("commits").help("Every N first-parent commits"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_debug_assert() {
+        // Catches clashes such as a global short flag reused by a subcommand
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_half_life_must_be_positive() {
+        let parse = |half_life: &str| {
+            Cli::try_parse_from(["gtct", "experts", "src", "--half-life", half_life]).is_ok()
+        };
+        assert!(parse("90"));
+        assert!(parse("0.5"));
+        assert!(!parse("0"));
+        assert!(!parse("-30"));
+        assert!(!parse("inf"));
+        assert!(!parse("NaN"));
+        assert!(!parse("soon"));
     }
 }
@@ -1,22 +1,27 @@
 use serde::{Deserialize, Serialize};
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
     pub repository: Option<PathBuf>,
     pub teams: Vec<String>,
     pub default_days: Option<i64>,
-}
-
-impl Default for Config {
-    fn default() -> Self {
-        Config {
-            repository: None,
-            teams: Vec::new(),
-            default_days: None,
-        }
-    }
+    /// Issue tracker URL template with an `{id}` placeholder
+    pub issue_url: Option<String>,
+    /// Regex patterns for issue references (built-in defaults when empty)
+    pub issue_patterns: Vec<String>,
+    /// Branch other branches are compared with (main/master when unset)
+    pub main_branch: Option<String>,
+    /// Glob for tags that count as deployments (`v*` when unset)
+    pub release_tags: Option<String>,
+    /// `gtct roster` activity windows in days (built-in defaults when unset)
+    pub active_days: Option<i64>,
+    pub ramping_days: Option<i64>,
+    pub departed_days: Option<i64>,
+    /// Effort estimate session gap and lead-in, in minutes (built-in defaults when unset)
+    pub session_gap: Option<i64>,
+    pub session_lead_in: Option<i64>,
 }
 
 impl Config {
@@ -34,21 +39,48 @@ impl Config {
 
         let default_days = env::var("DEFAULT_DAYS").ok().and_then(|d| d.parse().ok());
 
-        Config {
+        let issue_url = env::var("ISSUE_URL").ok().filter(|url| !url.is_empty());
+
+        // Semicolon-separated, since commas are common in regexes (`\d{2,5}`)
+        let issue_patterns = env::var("ISSUE_PATTERNS")
+            .ok()
+            .map(|p| p.split(';').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+
+        let main_branch = env::var("MAIN_BRANCH").ok().filter(|b| !b.is_empty());
+
+        let release_tags = env::var("RELEASE_TAGS").ok().filter(|p| !p.is_empty());
+
+        let days_var = |name: &str| env::var(name).ok().and_then(|d| d.parse().ok());
+        let active_days = days_var("ROSTER_ACTIVE_DAYS");
+        let ramping_days = days_var("ROSTER_RAMPING_DAYS");
+        let departed_days = days_var("ROSTER_DEPARTED_DAYS");
+
+        let session_gap = env::var("SESSION_GAP_MINUTES").ok().and_then(|m| m.parse().ok());
+        let session_lead_in = env::var("SESSION_LEAD_IN_MINUTES").ok().and_then(|m| m.parse().ok());
+
+        Self {
             repository,
             teams,
             default_days,
+            issue_url,
+            issue_patterns,
+            main_branch,
+            release_tags,
+            active_days,
+            ramping_days,
+            departed_days,
+            session_gap,
+            session_lead_in,
         }
     }
 
-    /// Get the repository path, falling back to CLI argument or current directory
-    pub fn get_repo_path(&self, cli_repo: &PathBuf) -> PathBuf {
-        // If CLI repo is the default ".", prefer .env setting
-        if cli_repo.as_os_str() == "." {
-            self.repository.clone().unwrap_or_else(|| PathBuf::from("."))
-        } else {
-            cli_repo.clone()
-        }
+    /// Get the repository path, preferring the CLI argument, then .env, then the current directory
+    pub fn get_repo_path(&self, cli_repo: Option<&Path>) -> PathBuf {
+        cli_repo
+            .map(Path::to_path_buf)
+            .or_else(|| self.repository.clone())
+            .unwrap_or_else(|| PathBuf::from("."))
     }
 
     /// Filter contributors by configured teams
@@ -66,6 +98,17 @@ impl Config {
             .collect()
     }
 
+    /// Team a contributor belongs to: the first matching `GIT_TEAMS` entry, or their email domain
+    pub fn team_of(&self, email: &str) -> String {
+        self.teams
+            .iter()
+            .find(|team| email.contains(team.as_str()))
+            .cloned()
+            .unwrap_or_else(|| {
+                email.rsplit_once('@').map_or(email, |(_, domain)| domain).to_string()
+            })
+    }
+
     /// Get default days or use the provided value
     pub fn get_days(&self, cli_days: i64) -> i64 {
         if cli_days != 0 && cli_days != 30 {
@@ -97,6 +140,18 @@ mod tests {
         assert_eq!(path, PathBuf::from("."));
     }
 
+    #[test]
+    fn test_get_repo_path_precedence() {
+        let config = Config {
+            repository: Some(PathBuf::from("/from/env")),
+            ..Default::default()
+        };
+        // --repo wins over GIT_REPO_PATH, even when it is the current directory
+        assert_eq!(config.get_repo_path(Some(Path::new("/from/cli"))), PathBuf::from("/from/cli"));
+        assert_eq!(config.get_repo_path(Some(Path::new("."))), PathBuf::from("."));
+        assert_eq!(config.get_repo_path(None), PathBuf::from("/from/env"));
+    }
+
     #[test]
     fn test_filter_by_teams_empty() {
         let config = Config::default();
@@ -115,4 +170,14 @@ mod tests {
         let filtered = config.filter_by_teams(items, |s| s);
         assert_eq!(filtered, vec!["alice@example.com"]);
     }
+
+    #[test]
+    fn test_team_of() {
+        let config = Config {
+            teams: vec!["platform".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(config.team_of("alice@platform.example.com"), "platform");
+        assert_eq!(config.team_of("bob@other.org"), "other.org");
+    }
 }
@@ -0,0 +1,342 @@
+use super::OutputFormat;
+use crate::stats::{Changelog, ChangelogEntry};
+
+const MARKDOWN_DOCUMENT: &str = "## {{title}} ({{date}})
+
+{{breaking}}
+
+{{sections}}
+
+### Contributors
+
+{{contributors}}
+
+{{first_time_contributors}}
+";
+
+const MARKDOWN_ENTRY: &str = "- {{scope}}{{description}} ({{hash}}){{issues}}";
+
+const MARKDOWN_SCOPE: &str = "#### {{scope}}
+
+{{entries}}";
+
+const TEXT_DOCUMENT: &str = "{{title}} ({{date}})
+
+{{breaking}}
+
+{{sections}}
+
+Contributors:
+{{contributors}}
+
+{{first_time_contributors}}
+";
+
+const TEXT_ENTRY: &str = "  * {{scope}}{{description}} ({{hash}}){{issues}}";
+
+const TEXT_SCOPE: &str = "  {{scope}}:
+{{entries}}";
+
+/// Document, per-scope and per-commit templates with `{{placeholder}}` fields
+///
+/// Document fields: `title`, `date`, `range`, `breaking`, `sections`, `contributors`,
+/// `first_time_contributors`. Scope fields: `type`, `scope`, `entries`. Entry fields: `hash`,
+/// `type`, `scope`, `description`, `author`, `email`, `issues`; an entry's `scope` is only
+/// filled where entries aren't grouped under a scope heading (breaking changes).
+#[derive(Debug, Clone)]
+pub struct ChangelogTemplate {
+    pub document: String,
+    pub scope: String,
+    pub entry: String,
+}
+
+impl ChangelogTemplate {
+    pub fn for_format(format: OutputFormat) -> Self {
+        let (document, scope, entry) = match format {
+            OutputFormat::Markdown => (MARKDOWN_DOCUMENT, MARKDOWN_SCOPE, MARKDOWN_ENTRY),
+            _ => (TEXT_DOCUMENT, TEXT_SCOPE, TEXT_ENTRY),
+        };
+        Self {
+            document: document.to_string(),
+            scope: scope.to_string(),
+            entry: entry.to_string(),
+        }
+    }
+}
+
+/// Replace `{{name}}` placeholders in one pass, so braces inside the values (a commit
+/// subject mentioning `{{hash}}`) are left as they are; unknown placeholders stay too
+fn fill(template: &str, fields: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let field = after.find("}}").and_then(|end| {
+            let name = &after[..end];
+            let (_, value) = fields.iter().find(|(field, _)| *field == name)?;
+            Some((value, end))
+        });
+        if let Some((value, end)) = field {
+            out.push_str(value);
+            rest = &after[end + 2..];
+        } else {
+            out.push_str("{{");
+            rest = after;
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Collapse the blank lines left behind by empty placeholders
+fn tidy(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blank_run = 0;
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 || out.is_empty() {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out.trim_end().to_string() + "\n"
+}
+
+/// One commit line; `show_scope` prefixes the scope when it has no heading of its own
+fn render_entry(
+    entry: &ChangelogEntry,
+    template: &str,
+    markdown: bool,
+    show_scope: bool,
+) -> String {
+    let scope = entry.scope.as_ref().filter(|_| show_scope).map_or_else(String::new, |scope| {
+        if markdown {
+            format!("**{scope}:** ")
+        } else {
+            format!("{scope}: ")
+        }
+    });
+    let issues = entry
+        .issues
+        .iter()
+        .map(|issue| match (&issue.url, markdown) {
+            (Some(url), true) => format!("[{}]({url})", issue.key),
+            (Some(url), false) => format!("{} <{url}>", issue.key),
+            (None, _) => issue.key.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let issues = if issues.is_empty() {
+        issues
+    } else {
+        format!(" ({issues})")
+    };
+
+    fill(
+        template,
+        &[
+            ("hash", entry.hash.clone()),
+            ("type", entry.kind.to_string()),
+            ("scope", scope),
+            ("description", entry.description.clone()),
+            ("author", entry.author.clone()),
+            ("email", entry.email.clone()),
+            ("issues", issues),
+        ],
+    )
+}
+
+/// Render a changelog as Markdown, plain text or JSON
+pub fn render_changelog(
+    changelog: &Changelog,
+    format: OutputFormat,
+    template: &ChangelogTemplate,
+) -> Result<String, serde_json::Error> {
+    if format == OutputFormat::Json {
+        return serde_json::to_string_pretty(changelog);
+    }
+
+    let markdown = format == OutputFormat::Markdown;
+    let heading = |title: &str| {
+        if markdown {
+            format!("### {title}")
+        } else {
+            format!("{title}:")
+        }
+    };
+    let bullet = if markdown { "-" } else { "  *" };
+
+    let sections = changelog
+        .sections
+        .iter()
+        .map(|section| {
+            let groups: Vec<String> = section
+                .scopes
+                .iter()
+                .map(|group| {
+                    let entries = group
+                        .entries
+                        .iter()
+                        .map(|e| render_entry(e, &template.entry, markdown, false))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    match &group.scope {
+                        Some(scope) => fill(
+                            &template.scope,
+                            &[
+                                ("type", section.kind.to_string()),
+                                ("scope", scope.clone()),
+                                ("entries", entries),
+                            ],
+                        ),
+                        None => entries,
+                    }
+                })
+                .collect();
+            format!("{}\n\n{}\n", heading(&section.title), groups.join("\n\n"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let breaking: Vec<String> = changelog
+        .breaking_changes()
+        .map(|entry| render_entry(entry, &template.entry, markdown, true))
+        .collect();
+    let breaking = if breaking.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n\n{}", heading("⚠ BREAKING CHANGES"), breaking.join("\n"))
+    };
+
+    let contributors = changelog
+        .contributors
+        .iter()
+        .map(|c| {
+            let noun = if c.commits == 1 { "commit" } else { "commits" };
+            format!("{bullet} {} ({} {noun})", c.name, c.commits)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let first_timers: Vec<String> = changelog
+        .contributors
+        .iter()
+        .filter(|c| c.first_time)
+        .map(|c| format!("{bullet} {} made their first contribution", c.name))
+        .collect();
+    let first_time_contributors = if first_timers.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n\n{}", heading("New Contributors"), first_timers.join("\n"))
+    };
+
+    let title = if changelog.to == "HEAD" {
+        "Unreleased"
+    } else {
+        &changelog.to
+    };
+    let range = changelog
+        .from
+        .as_ref()
+        .map_or_else(|| changelog.to.clone(), |from| format!("{from}..{}", changelog.to));
+
+    Ok(tidy(&fill(
+        &template.document,
+        &[
+            ("title", title.to_string()),
+            ("date", changelog.date.format("%Y-%m-%d").to_string()),
+            ("range", range),
+            ("breaking", breaking),
+            ("sections", sections),
+            ("contributors", contributors),
+            ("first_time_contributors", first_time_contributors),
+        ],
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{
+        changelog::{ChangelogScope, ChangelogSection},
+        CommitType,
+    };
+    use chrono::Utc;
+
+    fn entry(scope: Option<&str>, description: &str, breaking: bool) -> ChangelogEntry {
+        ChangelogEntry {
+            hash: "abc1234".to_string(),
+            kind: CommitType::Feature,
+            scope: scope.map(str::to_string),
+            breaking,
+            description: description.to_string(),
+            author: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+            date: Utc::now(),
+            issues: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_fill_single_pass() {
+        let fields = [
+            ("description", "Document {{hash}} and {{author}}".to_string()),
+            ("hash", "abc1234".to_string()),
+            ("author", "Alice".to_string()),
+        ];
+        assert_eq!(
+            fill("- {{description}} ({{hash}}, {{author}}) {{unknown}}", &fields),
+            "- Document {{hash}} and {{author}} (abc1234, Alice) {{unknown}}"
+        );
+        assert_eq!(fill("{{hash", &fields), "{{hash");
+    }
+
+    #[test]
+    fn test_render_scopes() -> Result<(), serde_json::Error> {
+        let changelog = Changelog {
+            from: None,
+            to: "v1.0.0".to_string(),
+            date: Utc::now(),
+            sections: vec![ChangelogSection {
+                kind: CommitType::Feature,
+                title: "Features".to_string(),
+                scopes: vec![
+                    ChangelogScope {
+                        scope: None,
+                        entries: vec![entry(None, "Add search", false)],
+                    },
+                    ChangelogScope {
+                        scope: Some("cli".to_string()),
+                        entries: vec![entry(Some("cli"), "Add --json", true)],
+                    },
+                ],
+            }],
+            contributors: Vec::new(),
+        };
+
+        let mut template = ChangelogTemplate::for_format(OutputFormat::Markdown);
+        template.document = "{{breaking}}\n\n{{sections}}".to_string();
+        let markdown = render_changelog(&changelog, OutputFormat::Markdown, &template)?;
+        // Grouped entries sit under their scope; the flat breaking list keeps the prefix
+        assert_eq!(
+            markdown,
+            "### ⚠ BREAKING CHANGES\n\n- **cli:** Add --json (abc1234)\n\n### Features\n\n\
+             - Add search (abc1234)\n\n#### cli\n\n- Add --json (abc1234)\n"
+        );
+
+        template.scope = "{{type}}/{{scope}}: {{entries}}".to_string();
+        template.entry = "{{description}}".to_string();
+        let custom = render_changelog(&changelog, OutputFormat::Markdown, &template)?;
+        assert!(custom.ends_with("### Features\n\nAdd search\n\nfeat/cli: Add --json\n"));
+
+        let json = render_changelog(&changelog, OutputFormat::Json, &template)?;
+        assert!(json.contains("\"scopes\""));
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use super::{
+    svg::{bar_chart, calendar, contributor_share, hour_bars, pie_chart, trend_bars, weekday_bars},
+    ReportData,
+};
+use crate::stats::{ContributorStats, GrowthSample, PeriodActivity, TimeStats};
+use chrono::Local;
+use colored::Colorize;
+use resvg::{
+    tiny_skia,
+    usvg::{
+        self,
+        fontdb::{Family, Query},
+    },
+};
+use std::{error::Error, fs, path::PathBuf};
+
+/// PNGs are rendered at twice the SVG size so they stay sharp on slides
+const PNG_SCALE: f32 = 2.0;
+
+/// Tried in order when the rasterizer's default sans-serif font (Arial) isn't installed
+const SANS_FALLBACKS: [&str; 5] = [
+    "Helvetica",
+    "DejaVu Sans",
+    "Liberation Sans",
+    "Noto Sans",
+    "Hiragino Sans",
+];
+
+/// One chart as a standalone SVG document
+pub struct Chart {
+    /// File name without extension, e.g. `hours`
+    pub name: &'static str,
+    pub svg: String,
+}
+
+/// Commits by hour, by weekday and the contribution calendar
+pub fn time_charts(time: &TimeStats) -> Vec<Chart> {
+    vec![
+        Chart {
+            name: "hours",
+            svg: bar_chart(&hour_bars(time), "#22c55e"),
+        },
+        Chart {
+            name: "weekdays",
+            svg: bar_chart(&weekday_bars(time), "#06b6d4"),
+        },
+        Chart {
+            name: "calendar",
+            svg: calendar(&time.daily, Local::now().date_naive()),
+        },
+    ]
+}
+
+/// Commits per period
+pub fn trend_chart(trends: &[PeriodActivity]) -> Chart {
+    Chart {
+        name: "trends",
+        svg: bar_chart(&trend_bars(trends), "#6366f1"),
+    }
+}
+
+/// Lines of code at each growth sample
+pub fn growth_chart(samples: &[GrowthSample]) -> Chart {
+    let bars: Vec<(String, usize)> = samples.iter().map(|s| (s.label.clone(), s.lines)).collect();
+    Chart {
+        name: "growth",
+        svg: bar_chart(&bars, "#f59e0b"),
+    }
+}
+
+/// Each contributor's share of the commits
+pub fn share_chart(stats: &[ContributorStats]) -> Chart {
+    Chart {
+        name: "contributors",
+        svg: pie_chart(&contributor_share(stats)),
+    }
+}
+
+/// Every chart a report has
+pub fn report_charts(report: &ReportData) -> Vec<Chart> {
+    let mut charts = time_charts(&report.time);
+    charts.push(trend_chart(&report.trends));
+    charts.push(share_chart(&report.contributors));
+    charts
+}
+
+/// Where `--chart-out` writes charts, and whether PNGs go next to the SVGs
+pub struct ChartOutput {
+    pub dir: PathBuf,
+    pub png: bool,
+}
+
+impl ChartOutput {
+    /// Write each chart as `<name>.svg` (and `<name>.png`), creating the directory if needed
+    pub fn write(&self, charts: &[Chart]) -> Result<(), Box<dyn Error>> {
+        fs::create_dir_all(&self.dir)?;
+        let options = self.png.then(png_options);
+        for chart in charts {
+            let path = self.dir.join(format!("{}.svg", chart.name));
+            fs::write(&path, &chart.svg)?;
+            eprintln!("{} {}", "✓ Chart written to".green(), path.display());
+
+            if let Some(options) = &options {
+                let path = path.with_extension("png");
+                fs::write(&path, rasterize(&chart.svg, options)?)?;
+                eprintln!("{} {}", "✓ Chart written to".green(), path.display());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rasterizer options with the system fonts, mapping "sans-serif" to one that is installed
+fn png_options() -> usvg::Options<'static> {
+    let mut options = usvg::Options::default();
+    let fonts = options.fontdb_mut();
+    fonts.load_system_fonts();
+    let installed = |family: Family| {
+        let families = [family];
+        fonts
+            .query(&Query {
+                families: &families,
+                ..Query::default()
+            })
+            .is_some()
+    };
+    if !installed(Family::SansSerif) {
+        let fallback = SANS_FALLBACKS
+            .iter()
+            .find(|name| installed(Family::Name(name)))
+            .map(ToString::to_string)
+            .or_else(|| {
+                fonts
+                    .faces()
+                    .find_map(|face| face.families.first().map(|(name, _)| name.clone()))
+            });
+        if let Some(family) = fallback {
+            fonts.set_sans_serif_family(family);
+        }
+    }
+    options
+}
+
+/// Render an SVG document to PNG bytes on a white background
+fn rasterize(svg: &str, options: &usvg::Options) -> Result<Vec<u8>, Box<dyn Error>> {
+    let tree = usvg::Tree::from_str(svg, options)?;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(PNG_SCALE)
+        .ok_or("chart is too large to rasterize")?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or("chart is too large to rasterize")?;
+    pixmap.fill(tiny_skia::Color::WHITE);
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(PNG_SCALE, PNG_SCALE),
+        &mut pixmap.as_mut(),
+    );
+    Ok(pixmap.encode_png()?)
+}
@@ -1,80 +1,265 @@
-use crate::stats::{ContributorStats, FileStats, TimeStats};
-use colored::*;
+use super::OutputFormat;
+use crate::stats::{
+    median, releases_per_week, size_histogram, ActivityStatus, ActivityWindows, AuthorProfile,
+    BinaryStats, BlobInfo, BranchInfo, CommitBytes, CommitType, ContributorGroup,
+    ContributorIssues, ContributorStats, DeliveryStats, ExpertStats, FileStats, FormattingReport,
+    GrowthSample, Interval, IssueReport, LanguageStats, LeadTimeStats, MergeInfo, OnboardingReport,
+    PullRequestReport, RampUp, RampWeek, Release, ReleaseSummary, ReworkReport, ReworkStats,
+    RosterEntry, RosterReport, SizeReport, SurvivalStats, TimeStats, TypeBreakdown, CHECKPOINTS,
+    SIZE_BUCKETS,
+};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
 use comfy_table::{presets::UTF8_FULL, Cell, CellAlignment, Color, Table};
 use git2::Repository;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+};
 
-pub fn display_contributors(stats: &[ContributorStats]) {
-    println!("\n{}\n", "📊 Contributor Statistics".bold().cyan());
+fn markdown() -> bool {
+    super::output_format() == OutputFormat::Markdown
+}
 
+/// Box-drawn table for the terminal; print it with `render` so Markdown output converts it
+fn new_table() -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
+    table
+}
+
+/// A table in the current output style: as built for the terminal, GitHub-flavored for Markdown
+fn render(table: &Table) -> String {
+    if markdown() {
+        super::markdown::from_table(table)
+    } else {
+        table.to_string()
+    }
+}
+
+/// Title of a command's output
+fn title(text: &str) -> String {
+    if markdown() {
+        format!("## {text}")
+    } else {
+        text.bold().cyan().to_string()
+    }
+}
+
+/// Heading of a table or chart within the output
+fn heading(text: &str) -> String {
+    if markdown() {
+        format!("### {}", text.trim_end_matches(':'))
+    } else {
+        text.bold().to_string()
+    }
+}
+
+/// Label of a "label: value" line, which becomes a list item in Markdown
+fn label(text: &str) -> String {
+    if markdown() {
+        format!("- **{text}**")
+    } else {
+        text.bold().to_string()
+    }
+}
+
+/// Start of lines that must keep their layout, such as bar charts (a code fence in Markdown)
+fn begin_block() {
+    if markdown() {
+        println!("```text");
+    }
+}
+
+/// End of a block started with `begin_block`
+fn end_block() {
+    if markdown() {
+        println!("```");
+    }
+}
+
+#[allow(clippy::cast_possible_wrap)]
+pub fn display_contributors(stats: &[ContributorStats]) {
+    println!("\n{}\n", title("📊 Contributor Statistics"));
+
+    let mut table = new_table();
     table.set_header(vec![
         Cell::new("Contributor").fg(Color::Cyan),
         Cell::new("Commits").fg(Color::Green),
+        Cell::new("PRs").fg(Color::Green),
         Cell::new("Additions").fg(Color::Green),
         Cell::new("Deletions").fg(Color::Red),
         Cell::new("Files").fg(Color::Yellow),
         Cell::new("Net").fg(Color::Magenta),
+        Cell::new("Active Days").fg(Color::Yellow),
+        Cell::new("Effort").fg(Color::Yellow),
+        Cell::new("First Commit").fg(Color::Cyan),
+        Cell::new("Last Commit").fg(Color::Cyan),
     ]);
 
     for stat in stats {
         let net = stat.additions as i64 - stat.deletions as i64;
         let net_str = if net >= 0 {
-            format!("+{}", net).green().to_string()
+            format!("+{net}").green().to_string()
         } else {
-            format!("{}", net).red().to_string()
+            format!("{net}").red().to_string()
         };
 
         table.add_row(vec![
-            Cell::new(&format!("{} <{}>", stat.name, stat.email)),
+            Cell::new(format!("{} <{}>", stat.name, stat.email)),
             Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.prs.to_string()).set_alignment(CellAlignment::Right),
             Cell::new(stat.additions.to_string()).set_alignment(CellAlignment::Right),
             Cell::new(stat.deletions.to_string()).set_alignment(CellAlignment::Right),
             Cell::new(stat.files_changed.to_string()).set_alignment(CellAlignment::Right),
             Cell::new(net_str).set_alignment(CellAlignment::Right),
+            Cell::new(stat.active_days.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format_effort(stat.effort_minutes)).set_alignment(CellAlignment::Right),
+            Cell::new(format_date(stat.first_commit)),
+            Cell::new(format_date(stat.last_commit)),
+        ]);
+    }
+
+    println!("{}", render(&table));
+}
+
+/// Estimated effort in hours, e.g. "12.5h"
+#[allow(clippy::cast_precision_loss)]
+pub fn format_effort(minutes: usize) -> String {
+    format!("{:.1}h", minutes as f64 / 60.0)
+}
+
+/// Date as YYYY-MM-DD, or "-" when unknown
+pub fn format_date(date: Option<DateTime<Utc>>) -> String {
+    date.map_or_else(|| "-".to_string(), |date| date.format("%Y-%m-%d").to_string())
+}
+
+/// Contributor table with each person's language mix (share of lines changed)
+pub fn display_contributor_languages(
+    stats: &[ContributorStats],
+    mix: &HashMap<String, Vec<(String, usize)>>,
+) {
+    println!("\n{}\n", title("🗣  Contributor Language Mix"));
+
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Churn").fg(Color::Magenta),
+        Cell::new("Languages").fg(Color::Yellow),
+    ]);
+
+    for stat in stats {
+        let languages = mix.get(&format!("{}|{}", stat.name, stat.email));
+        let total: usize = languages.map_or(0, |l| l.iter().map(|(_, lines)| lines).sum());
+        let summary = languages
+            .map(|languages| {
+                languages
+                    .iter()
+                    .take(4)
+                    .map(|(language, lines)| {
+                        let percent = (lines * 100).checked_div(total).unwrap_or(0);
+                        format!("{language} {percent}%")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+
+        table.add_row(vec![
+            Cell::new(format!("{} <{}>", stat.name, stat.email)),
+            Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(total.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(summary),
+        ]);
+    }
+
+    println!("{}", render(&table));
+}
+
+pub fn display_contributor_groups(groups: &[ContributorGroup]) {
+    println!("\n{}\n", title("📂 Contributor Statistics by Group"));
+
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Path").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Additions").fg(Color::Green),
+        Cell::new("Deletions").fg(Color::Red),
+        Cell::new("Contributors").fg(Color::Yellow),
+        Cell::new("Top Contributors").fg(Color::Magenta),
+    ]);
+
+    for group in groups {
+        let top = group
+            .contributors
+            .iter()
+            .take(3)
+            .map(|c| format!("{} ({})", c.name, c.commits))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        table.add_row(vec![
+            Cell::new(&group.path),
+            Cell::new(group.commits().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(group.additions().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(group.deletions().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(group.contributors.len().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(top),
         ]);
     }
 
-    println!("{}", table);
+    println!("{}", render(&table));
 }
 
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
 pub fn display_time_analysis(time_stats: &TimeStats) {
-    println!("\n{}\n", "⏰ Time-based Commit Analysis".bold().cyan());
+    println!("\n{}\n", title("⏰ Time-based Commit Analysis"));
 
     // Hour distribution
-    println!("{}", "Commits by Hour:".bold());
+    println!("{}", heading("Commits by Hour:"));
     let max_hour_commits = time_stats.hour_distribution.values().max().unwrap_or(&1);
+    begin_block();
     for hour in 0..24 {
         let count = time_stats.hour_distribution.get(&hour).unwrap_or(&0);
         let bar_length = ((*count as f64 / *max_hour_commits as f64) * 50.0) as usize;
         let bar = "█".repeat(bar_length);
-        println!("{:02}:00 │ {} {}", hour, bar.green(), format!("({})", count).dimmed());
+        println!("{:02}:00 │ {} {}", hour, bar.green(), format!("({count})").dimmed());
     }
+    end_block();
 
     println!();
 
     // Day distribution
-    println!("{}", "Commits by Day of Week:".bold());
+    println!("{}", heading("Commits by Day of Week:"));
     let days = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
     let max_day_commits = time_stats.day_distribution.values().max().unwrap_or(&1);
-    for (idx, day) in days.iter().enumerate() {
-        let count = time_stats.day_distribution.get(&(idx as u32)).unwrap_or(&0);
+    begin_block();
+    for (idx, day) in (0u32..).zip(days.iter()) {
+        let count = time_stats.day_distribution.get(&idx).unwrap_or(&0);
         let bar_length = ((*count as f64 / *max_day_commits as f64) * 50.0) as usize;
         let bar = "█".repeat(bar_length);
-        println!("{} │ {} {}", day, bar.cyan(), format!("({})", count).dimmed());
+        println!("{} │ {} {}", day, bar.cyan(), format!("({count})").dimmed());
     }
+    end_block();
     println!();
 }
 
 pub fn display_file_changes(files: &[FileStats], top: usize) {
-    println!("\n{}\n", "📁 Most Changed Files".bold().cyan());
+    println!("\n{}\n", title("📁 Most Changed Files"));
 
-    let mut table = Table::new();
-    table.load_preset(UTF8_FULL);
+    let mut table = new_table();
     table.set_header(vec![
         Cell::new("Rank").fg(Color::Cyan),
-        Cell::new("File Path").fg(Color::Cyan),
+        Cell::new("Path").fg(Color::Cyan),
         Cell::new("Changes").fg(Color::Green),
+        Cell::new("Added").fg(Color::Green),
+        Cell::new("Deleted").fg(Color::Red),
+        Cell::new("Churn").fg(Color::Magenta),
         Cell::new("Contributors").fg(Color::Yellow),
     ]);
 
@@ -83,51 +268,1458 @@ pub fn display_file_changes(files: &[FileStats], top: usize) {
             Cell::new((idx + 1).to_string()).set_alignment(CellAlignment::Right),
             Cell::new(&file.path),
             Cell::new(file.changes.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(file.additions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(file.deletions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(file.churn().to_string()).set_alignment(CellAlignment::Right),
             Cell::new(file.contributors.len().to_string()).set_alignment(CellAlignment::Right),
         ]);
     }
 
-    println!("{}", table);
+    println!("{}", render(&table));
 }
 
-pub fn display_summary(
-    repo: &Repository,
-    stats: &[ContributorStats],
-    days: i64,
-) -> Result<(), git2::Error> {
-    println!("\n{}\n", "📈 Team Summary".bold().cyan());
+pub fn display_summary(repo: &Repository, stats: &[ContributorStats], days: i64) {
+    println!("\n{}\n", title("📈 Team Summary"));
 
     let total_commits: usize = stats.iter().map(|s| s.commits).sum();
     let total_additions: usize = stats.iter().map(|s| s.additions).sum();
     let total_deletions: usize = stats.iter().map(|s| s.deletions).sum();
     let total_contributors = stats.len();
+    let active_days: usize = stats.iter().map(|s| s.active_days).sum();
+    let sessions: usize = stats.iter().map(|s| s.sessions).sum();
+    let effort_minutes: usize = stats.iter().map(|s| s.effort_minutes).sum();
 
     let period = if days == 0 {
         "All Time".to_string()
     } else {
-        format!("Last {} days", days)
+        format!("Last {days} days")
     };
 
-    println!("{}: {}", "Period".bold(), period.yellow());
-    println!("{}: {}", "Total Contributors".bold(), total_contributors.to_string().green());
-    println!("{}: {}", "Total Commits".bold(), total_commits.to_string().cyan());
-    println!("{}: {}", "Lines Added".bold(), format!("+{}", total_additions).green());
-    println!("{}: {}", "Lines Deleted".bold(), format!("-{}", total_deletions).red());
+    println!("{}: {}", label("Period"), period.yellow());
+    println!("{}: {}", label("Total Contributors"), total_contributors.to_string().green());
+    println!("{}: {}", label("Total Commits"), total_commits.to_string().cyan());
+    println!("{}: {}", label("Lines Added"), format!("+{total_additions}").green());
+    println!("{}: {}", label("Lines Deleted"), format!("-{total_deletions}").red());
     println!(
         "{}: {}",
-        "Net Change".bold(),
+        label("Net Change"),
         if total_additions >= total_deletions {
             format!("+{}", total_additions - total_deletions).green()
         } else {
             format!("-{}", total_deletions - total_additions).red()
         }
     );
+    println!("{}: {}", label("Active Days"), format!("{active_days} person-days").yellow());
+    println!(
+        "{}: {}",
+        label("Estimated Effort"),
+        format!("{} in {sessions} sessions", format_effort(effort_minutes)).yellow()
+    );
 
     // Repository info - show path instead of branch
     if let Some(path) = repo.path().parent() {
-        println!("{}: {}", "Repository".bold(), path.display().to_string().yellow());
+        println!("{}: {}", label("Repository"), path.display().to_string().yellow());
     }
 
     println!();
-    Ok(())
+}
+
+pub fn display_author_profile(profile: &AuthorProfile, top: usize) {
+    println!("\n{}\n", title(&format!("👤 {} <{}>", profile.name, profile.email)));
+
+    println!("{}: {}", label("First Commit"), format_date(profile.first_commit).yellow());
+    println!("{}: {}", label("Last Commit"), format_date(profile.last_commit).yellow());
+    println!("{}: {}", label("Commits"), profile.commits.to_string().cyan());
+    println!("{}: {}", label("Lines Added"), format!("+{}", profile.additions).green());
+    println!("{}: {}", label("Lines Deleted"), format!("-{}", profile.deletions).red());
+    println!(
+        "{}: {}",
+        label("Typical Commit Size"),
+        format!("{} lines", profile.typical_commit_size()).magenta()
+    );
+
+    println!("\n{}", heading("Commits by Month:"));
+    let max_month = profile.monthly_commits.values().max().copied().unwrap_or(1);
+    begin_block();
+    for (month, count) in &profile.monthly_commits {
+        let bar = "█".repeat(count * 50 / max_month);
+        println!("{month} │ {} {}", bar.green(), format!("({count})").dimmed());
+    }
+    end_block();
+
+    println!("\n{}", heading("Languages:"));
+    let total_lines: usize = profile.languages.iter().map(|(_, lines)| lines).sum();
+    begin_block();
+    for (kind, lines) in profile.languages.iter().take(top) {
+        let percent = (lines * 100).checked_div(total_lines).unwrap_or(0);
+        println!("  {:<12} {:>8} lines {}", kind, lines, format!("({percent}%)").dimmed());
+    }
+    end_block();
+
+    println!("\n{}", heading("Top Directories:"));
+    display_file_rows(&profile.directories, top);
+    println!("{}", heading("Top Files:"));
+    display_file_rows(&profile.files, top);
+
+    display_time_analysis(&profile.time);
+}
+
+fn display_file_rows(files: &[FileStats], top: usize) {
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Path").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Added").fg(Color::Green),
+        Cell::new("Deleted").fg(Color::Red),
+    ]);
+
+    for file in files.iter().take(top) {
+        table.add_row(vec![
+            Cell::new(&file.path),
+            Cell::new(file.changes.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(file.additions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(file.deletions.to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+
+    println!("{}\n", render(&table));
+}
+
+pub fn display_experts(path: &str, experts: &[ExpertStats], top: usize) {
+    println!("\n{}\n", title(&format!("🧠 Who knows {path}?")));
+
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Rank").fg(Color::Cyan),
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("Score").fg(Color::Magenta),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Additions").fg(Color::Green),
+        Cell::new("Deletions").fg(Color::Red),
+        Cell::new("Last Change").fg(Color::Yellow),
+    ]);
+
+    for (idx, expert) in experts.iter().take(top).enumerate() {
+        let stat = &expert.contributor;
+        let last_change = expert
+            .last_change
+            .map_or_else(String::new, |dt| dt.format("%Y-%m-%d").to_string());
+        table.add_row(vec![
+            Cell::new((idx + 1).to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format!("{} <{}>", stat.name, stat.email)),
+            Cell::new(format!("{:.1}", expert.score)).set_alignment(CellAlignment::Right),
+            Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.additions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.deletions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(last_change),
+        ]);
+    }
+
+    println!("{}", render(&table));
+}
+
+/// Ticket traceability: share of referenced commits, tickets per contributor, top tickets
+pub fn display_issues(report: &IssueReport, top: usize) {
+    println!("\n{}\n", title("🎫 Issue References"));
+
+    let total: usize = report.contributors.iter().map(|c| c.contributor.commits).sum();
+    let unreferenced: usize = report.contributors.iter().map(ContributorIssues::unreferenced).sum();
+    let percent = (unreferenced * 100).checked_div(total).unwrap_or(0);
+    println!(
+        "{}: {}",
+        label("Without a ticket"),
+        format!("{unreferenced} of {total} commits ({percent}%)").yellow()
+    );
+
+    println!("\n{}", heading("By Contributor:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("With Ticket").fg(Color::Green),
+        Cell::new("Without %").fg(Color::Red),
+        Cell::new("Tickets").fg(Color::Yellow),
+    ]);
+    for stat in &report.contributors {
+        let contributor = &stat.contributor;
+        let without = (stat.unreferenced() * 100).checked_div(contributor.commits).unwrap_or(0);
+        table.add_row(vec![
+            Cell::new(format!("{} <{}>", contributor.name, contributor.email)),
+            Cell::new(contributor.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.referenced.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format!("{without}%")).set_alignment(CellAlignment::Right),
+            Cell::new(stat.tickets.to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+
+    println!("{}", heading("Top Tickets by Churn:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Ticket").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Added").fg(Color::Green),
+        Cell::new("Deleted").fg(Color::Red),
+        Cell::new("Churn").fg(Color::Magenta),
+        Cell::new("Contributors").fg(Color::Yellow),
+    ]);
+    for ticket in report.tickets.iter().take(top) {
+        let label = ticket.issue.url.as_ref().map_or_else(
+            || ticket.issue.key.clone(),
+            |url| format!("{} ({url})", ticket.issue.key),
+        );
+        table.add_row(vec![
+            Cell::new(label),
+            Cell::new(ticket.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(ticket.additions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(ticket.deletions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(ticket.churn().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(ticket.contributors.len().to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+/// Reverts, fixups and rework by contributor, directory and period
+pub fn display_rework(report: &ReworkReport, window: i64) {
+    println!("\n{}\n", title("♻️  Rework"));
+
+    let deletions: usize = report.contributors.iter().map(|s| s.deletions).sum();
+    let rework: usize = report.contributors.iter().map(|s| s.rework).sum();
+    let percent = (rework * 100).checked_div(deletions).unwrap_or(0);
+    println!(
+        "{}: {}",
+        label(&format!("Rewritten within {window} days")),
+        format!("{rework} of {deletions} deleted/modified lines ({percent}%)").yellow()
+    );
+
+    println!("\n{}", heading("By Contributor:"));
+    display_rework_table("Contributor", &report.contributors, true);
+    println!("{}", heading("By Directory:"));
+    display_rework_table("Path", &report.directories, false);
+    println!("{}", heading("Over Time:"));
+    display_rework_table("Period", &report.timeline, false);
+
+    if report.reverts.is_empty() {
+        return;
+    }
+    println!("{}", heading("Reverts:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Revert").fg(Color::Cyan),
+        Cell::new("Author").fg(Color::Cyan),
+        Cell::new("Reverted").fg(Color::Red),
+        Cell::new("Reverted Author").fg(Color::Yellow),
+        Cell::new("Days").fg(Color::Magenta),
+        Cell::new("Summary").fg(Color::Cyan),
+    ]);
+    for revert in &report.reverts {
+        table.add_row(vec![
+            Cell::new(&revert.hash),
+            Cell::new(&revert.author),
+            Cell::new(&revert.reverted_hash),
+            Cell::new(&revert.reverted_author),
+            Cell::new(revert.days_to_revert.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(&revert.summary),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+fn display_rework_table(label: &str, stats: &[ReworkStats], show_reverted: bool) {
+    let mut table = new_table();
+
+    let mut header = vec![
+        Cell::new(label).fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Fixups").fg(Color::Yellow),
+        Cell::new("Reverts").fg(Color::Yellow),
+    ];
+    if show_reverted {
+        header.push(Cell::new("Reverted").fg(Color::Red));
+    }
+    header.extend([
+        Cell::new("Deleted").fg(Color::Red),
+        Cell::new("Rework").fg(Color::Magenta),
+        Cell::new("Rework %").fg(Color::Magenta),
+    ]);
+    table.set_header(header);
+
+    for stat in stats {
+        let mut row = vec![
+            Cell::new(&stat.label),
+            Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.fixups.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.reverts.to_string()).set_alignment(CellAlignment::Right),
+        ];
+        if show_reverted {
+            row.push(Cell::new(stat.reverted.to_string()).set_alignment(CellAlignment::Right));
+        }
+        row.extend([
+            Cell::new(stat.deletions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.rework.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format!("{}%", stat.rework_rate())).set_alignment(CellAlignment::Right),
+        ]);
+        table.add_row(row);
+    }
+
+    println!("{}\n", render(&table));
+}
+
+/// Survival of added lines by contributor, team, directory and the period they were written in
+pub fn display_survival(
+    contributors: &[SurvivalStats],
+    teams: &[SurvivalStats],
+    directories: &[SurvivalStats],
+    periods: &[SurvivalStats],
+) {
+    println!("\n{}\n", title("🌱 Code Survival"));
+    println!(
+        "{}",
+        "Share of added lines still present N days later and at HEAD (- = not old enough yet)"
+            .dimmed()
+    );
+
+    println!("\n{}", heading("By Contributor:"));
+    display_survival_table("Contributor", contributors);
+    println!("{}", heading("By Team:"));
+    display_survival_table("Team", teams);
+    println!("{}", heading("By Directory:"));
+    display_survival_table("Path", directories);
+    println!("{}", heading("By Period Written:"));
+    display_survival_table("Period", periods);
+}
+
+fn display_survival_table(label: &str, stats: &[SurvivalStats]) {
+    let mut table = new_table();
+
+    let mut header = vec![
+        Cell::new(label).fg(Color::Cyan),
+        Cell::new("Added").fg(Color::Green),
+    ];
+    header.extend(CHECKPOINTS.iter().map(|days| Cell::new(format!("{days}d")).fg(Color::Yellow)));
+    header.push(Cell::new("HEAD").fg(Color::Magenta));
+    table.set_header(header);
+
+    let percent = |rate: Option<usize>| rate.map_or_else(|| "-".to_string(), |r| format!("{r}%"));
+    for stat in stats {
+        let mut row = vec![
+            Cell::new(&stat.label),
+            Cell::new(stat.added.to_string()).set_alignment(CellAlignment::Right),
+        ];
+        row.extend(
+            stat.survival_rates()
+                .into_iter()
+                .map(|rate| Cell::new(percent(rate)).set_alignment(CellAlignment::Right)),
+        );
+        row.push(Cell::new(percent(stat.head_rate())).set_alignment(CellAlignment::Right));
+        table.add_row(row);
+    }
+
+    println!("{}\n", render(&table));
+}
+
+/// Horizontal bar chart of how many sizes fall into each `SIZE_BUCKETS` bucket
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn display_size_histogram(lines: impl IntoIterator<Item = usize>) {
+    let histogram = size_histogram(lines);
+    let max_count = histogram.iter().copied().max().unwrap_or(0).max(1);
+    begin_block();
+    for ((_, label), count) in SIZE_BUCKETS.iter().zip(histogram) {
+        let bar_length = ((count as f64 / max_count as f64) * 50.0) as usize;
+        let bar = "█".repeat(bar_length);
+        println!("{label:>9} │ {} {}", bar.green(), format!("({count})").dimmed());
+    }
+    end_block();
+    println!();
+}
+
+/// Commit size percentiles per contributor, a size histogram and the largest commits
+pub fn display_commit_sizes(report: &SizeReport, threshold: usize, top: usize) {
+    println!("\n{}\n", title("📏 Commit Sizes"));
+
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Median Lines").fg(Color::Yellow),
+        Cell::new("P90 Lines").fg(Color::Yellow),
+        Cell::new("Max Lines").fg(Color::Red),
+        Cell::new("Median Files").fg(Color::Magenta),
+        Cell::new("P90 Files").fg(Color::Magenta),
+        Cell::new("Max Files").fg(Color::Red),
+    ]);
+    for stat in &report.contributors {
+        table.add_row(vec![
+            Cell::new(format!("{} <{}>", stat.name, stat.email)),
+            Cell::new(stat.commits().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.median_lines().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.p90_lines().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.max_lines().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.median_files().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.p90_files().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.max_files().to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+
+    println!("{}", heading("Lines Changed per Commit:"));
+    display_size_histogram(report.contributors.iter().flat_map(|s| s.lines.iter().copied()));
+
+    println!("{}", heading(&format!("Outliers (> {threshold} lines):")));
+    if report.outliers.is_empty() {
+        println!("{}", "None".dimmed());
+        return;
+    }
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Commit").fg(Color::Cyan),
+        Cell::new("Author").fg(Color::Cyan),
+        Cell::new("Lines").fg(Color::Red),
+        Cell::new("Files").fg(Color::Yellow),
+        Cell::new("Likely Cause").fg(Color::Magenta),
+        Cell::new("Subject").fg(Color::Cyan),
+    ]);
+    for outlier in report.outliers.iter().take(top) {
+        table.add_row(vec![
+            Cell::new(&outlier.hash),
+            Cell::new(&outlier.author),
+            Cell::new(outlier.lines.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(outlier.files.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(outlier.reason.to_string()),
+            Cell::new(&outlier.summary),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+/// Whitespace churn per contributor and the commits that only change formatting
+pub fn display_formatting(report: &FormattingReport, top: usize) {
+    println!("\n{}\n", title("🧹 Formatting Changes"));
+
+    let lines: usize = report.contributors.iter().map(|s| s.lines).sum();
+    let formatting: usize = report.contributors.iter().map(|s| s.formatting_lines).sum();
+    let percent = (formatting * 100).checked_div(lines).unwrap_or(0);
+    println!(
+        "{}: {}",
+        label("Formatting churn"),
+        format!("{formatting} of {lines} changed lines ({percent}%)").yellow()
+    );
+
+    println!("\n{}", heading("By Contributor:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Formatting-only").fg(Color::Yellow),
+        Cell::new("Lines").fg(Color::Green),
+        Cell::new("Formatting Lines").fg(Color::Yellow),
+        Cell::new("Formatting %").fg(Color::Magenta),
+    ]);
+    for stat in &report.contributors {
+        table.add_row(vec![
+            Cell::new(format!("{} <{}>", stat.name, stat.email)),
+            Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.formatting_commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.lines.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.formatting_lines.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format!("{}%", stat.formatting_share())).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+
+    println!("{}", heading("Formatting-only Commits:"));
+    if report.commits.is_empty() {
+        println!("{}", "None".dimmed());
+        return;
+    }
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Commit").fg(Color::Cyan),
+        Cell::new("Author").fg(Color::Cyan),
+        Cell::new("Lines").fg(Color::Yellow),
+        Cell::new("Subject").fg(Color::Cyan),
+    ]);
+    for commit in report.commits.iter().take(top) {
+        table.add_row(vec![
+            Cell::new(&commit.hash),
+            Cell::new(&commit.author),
+            Cell::new(commit.lines.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(&commit.summary),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+/// Human-readable byte count (B, KiB, MiB, GiB)
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Binary file changes per contributor, team and period, and the largest blobs and commits
+pub fn display_binaries(
+    contributors: &[BinaryStats],
+    teams: &[BinaryStats],
+    periods: &[BinaryStats],
+    largest: &[BlobInfo],
+    commits: &[CommitBytes],
+    top: usize,
+) {
+    println!("\n{}\n", title("📦 Binary Files"));
+
+    let bytes: u64 = commits.iter().map(|c| c.bytes).sum();
+    let binary_bytes: u64 = contributors.iter().map(|s| s.bytes).sum();
+    println!("{}: {}", label("Bytes added"), format_bytes(bytes).yellow());
+    println!("{}: {}", label("Binary bytes added"), format_bytes(binary_bytes).yellow());
+
+    println!("\n{}", heading("By Contributor:"));
+    display_binaries_table("Contributor", contributors);
+    println!("{}", heading("By Team:"));
+    display_binaries_table("Team", teams);
+    println!("{}", heading("By Period:"));
+    display_binaries_table("Period", periods);
+
+    println!("{}", heading("Largest Commits:"));
+    display_commit_bytes(commits, top);
+
+    println!("{}", heading("Largest Blobs:"));
+    if largest.is_empty() {
+        println!("{}", "None".dimmed());
+        return;
+    }
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Path").fg(Color::Cyan),
+        Cell::new("Size").fg(Color::Yellow),
+        Cell::new("Binary").fg(Color::Magenta),
+        Cell::new("Commit").fg(Color::Cyan),
+        Cell::new("Author").fg(Color::Cyan),
+        Cell::new("Period").fg(Color::Green),
+    ]);
+    for blob in largest.iter().take(top) {
+        table.add_row(vec![
+            Cell::new(&blob.path),
+            Cell::new(format_bytes(blob.size)).set_alignment(CellAlignment::Right),
+            Cell::new(if blob.binary { "yes" } else { "no" }),
+            Cell::new(&blob.hash),
+            Cell::new(&blob.author),
+            Cell::new(&blob.period),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+/// Commits by bytes of new blobs written
+fn display_commit_bytes(commits: &[CommitBytes], top: usize) {
+    if commits.is_empty() {
+        println!("{}\n", "None".dimmed());
+        return;
+    }
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Commit").fg(Color::Cyan),
+        Cell::new("Bytes Added").fg(Color::Yellow),
+        Cell::new("Binary").fg(Color::Magenta),
+        Cell::new("New Blobs").fg(Color::Green),
+        Cell::new("Author").fg(Color::Cyan),
+        Cell::new("Period").fg(Color::Green),
+        Cell::new("Summary").fg(Color::Cyan),
+    ]);
+    for commit in commits.iter().take(top) {
+        table.add_row(vec![
+            Cell::new(&commit.hash),
+            Cell::new(format_bytes(commit.bytes)).set_alignment(CellAlignment::Right),
+            Cell::new(format_bytes(commit.binary_bytes)).set_alignment(CellAlignment::Right),
+            Cell::new(commit.blobs.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(&commit.author),
+            Cell::new(&commit.period),
+            Cell::new(&commit.summary),
+        ]);
+    }
+    println!("{}\n", render(&table));
+}
+
+fn display_binaries_table(label: &str, stats: &[BinaryStats]) {
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new(label).fg(Color::Cyan),
+        Cell::new("Added").fg(Color::Green),
+        Cell::new("Modified").fg(Color::Yellow),
+        Cell::new("Deleted").fg(Color::Red),
+        Cell::new("Bytes Added").fg(Color::Magenta),
+    ]);
+    for stat in stats {
+        table.add_row(vec![
+            Cell::new(&stat.label),
+            Cell::new(stat.added.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.modified.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.deleted.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format_bytes(stat.bytes)).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+}
+
+/// Codebase size at each sample, a LOC chart and the largest languages and directories
+pub fn display_growth(samples: &[GrowthSample], top: usize) {
+    println!("\n{}\n", title("📈 Repository Growth"));
+    if samples.is_empty() {
+        println!("{}", "No samples".dimmed());
+        return;
+    }
+
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Sample").fg(Color::Cyan),
+        Cell::new("Commit").fg(Color::Cyan),
+        Cell::new("Date").fg(Color::Cyan),
+        Cell::new("Files").fg(Color::Green),
+        Cell::new("Lines").fg(Color::Green),
+        Cell::new("Change").fg(Color::Yellow),
+        Cell::new("Objects").fg(Color::Magenta),
+    ]);
+    let mut previous = None;
+    for sample in samples {
+        let change = previous.map_or_else(String::new, |lines: usize| {
+            if sample.lines >= lines {
+                format!("+{}", sample.lines - lines)
+            } else {
+                format!("-{}", lines - sample.lines)
+            }
+        });
+        table.add_row(vec![
+            Cell::new(&sample.label),
+            Cell::new(&sample.hash),
+            Cell::new(sample.date.format("%Y-%m-%d").to_string()),
+            Cell::new(sample.files.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(sample.lines.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(change).set_alignment(CellAlignment::Right),
+            Cell::new(format_bytes(sample.bytes)).set_alignment(CellAlignment::Right),
+        ]);
+        previous = Some(sample.lines);
+    }
+    println!("{}", render(&table));
+
+    println!("\n{}", heading("Lines of Code:"));
+    let max_lines = samples.iter().map(|s| s.lines).max().unwrap_or(0).max(1);
+    let width = samples.iter().map(|s| s.label.chars().count()).max().unwrap_or(0);
+    begin_block();
+    for sample in samples {
+        let bar = "█".repeat(sample.lines * 50 / max_lines);
+        println!(
+            "{:>width$} │ {} {}",
+            sample.label,
+            bar.green(),
+            format!("({})", sample.lines).dimmed()
+        );
+    }
+    end_block();
+
+    let latest = &samples[samples.len() - 1];
+    println!("\n{}", heading("By Language:"));
+    display_growth_breakdown("Language", samples, top, |s| &s.languages, &latest.languages);
+    println!("{}", heading("By Directory:"));
+    display_growth_breakdown("Directory", samples, top, |s| &s.directories, &latest.directories);
+}
+
+/// Lines per sample for the `top` largest keys at the latest sample
+fn display_growth_breakdown(
+    label: &str,
+    samples: &[GrowthSample],
+    top: usize,
+    lines: impl Fn(&GrowthSample) -> &BTreeMap<String, usize>,
+    latest: &BTreeMap<String, usize>,
+) {
+    let mut keys: Vec<(&String, &usize)> = latest.iter().collect();
+    keys.sort_by_key(|(_, lines)| Reverse(**lines));
+    let keys: Vec<&String> = keys.into_iter().take(top).map(|(key, _)| key).collect();
+
+    let mut table = new_table();
+    let mut header = vec![Cell::new(label).fg(Color::Cyan)];
+    header.extend(samples.iter().map(|s| Cell::new(&s.label).fg(Color::Green)));
+    table.set_header(header);
+    for key in keys {
+        let mut row = vec![Cell::new(key)];
+        row.extend(samples.iter().map(|s| {
+            Cell::new(lines(s).get(key).copied().unwrap_or(0).to_string())
+                .set_alignment(CellAlignment::Right)
+        }));
+        table.add_row(row);
+    }
+    println!("{}\n", render(&table));
+}
+
+/// Branches versus the main branch, with stale branches grouped by owner
+pub fn display_branches(main_branch: &str, branches: &[BranchInfo], stale_days: i64) {
+    println!("\n{}\n", title("🌿 Branches"));
+    println!("{}: {}", label("Compared with"), main_branch.yellow());
+
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Branch").fg(Color::Cyan),
+        Cell::new("Type").fg(Color::Cyan),
+        Cell::new("Last Commit").fg(Color::Cyan),
+        Cell::new("Author").fg(Color::Cyan),
+        Cell::new("Ahead").fg(Color::Green),
+        Cell::new("Behind").fg(Color::Red),
+        Cell::new("Merged").fg(Color::Magenta),
+        Cell::new("Age (days)").fg(Color::Yellow),
+        Cell::new("Idle (days)").fg(Color::Yellow),
+    ]);
+    for branch in branches {
+        let name = if branch.is_stale(stale_days) {
+            Cell::new(format!("{} (stale)", branch.name)).fg(Color::Yellow)
+        } else {
+            Cell::new(&branch.name)
+        };
+        table.add_row(vec![
+            name,
+            Cell::new(if branch.remote { "remote" } else { "local" }),
+            Cell::new(format!("{} {}", branch.last_commit.format("%Y-%m-%d"), branch.hash)),
+            Cell::new(&branch.author),
+            Cell::new(branch.ahead.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(branch.behind.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(if branch.merged { "yes" } else { "no" }),
+            Cell::new(branch.age_days.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(branch.idle_days.to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}", render(&table));
+
+    println!(
+        "\n{}",
+        heading(&format!("Stale Branches by Owner (no commits for over {stale_days} days):"))
+    );
+    let mut owners: Vec<(String, Vec<&BranchInfo>)> = Vec::new();
+    for branch in branches.iter().filter(|b| b.is_stale(stale_days)) {
+        let label = format!("{} <{}>", branch.author, branch.email);
+        match owners.iter_mut().find(|(name, _)| *name == label) {
+            Some((_, owned)) => owned.push(branch),
+            None => owners.push((label, vec![branch])),
+        }
+    }
+    if owners.is_empty() {
+        println!("{}", "None".dimmed());
+        return;
+    }
+    owners.sort_by_key(|(_, owned)| Reverse(owned.len()));
+
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Owner").fg(Color::Cyan),
+        Cell::new("Stale").fg(Color::Yellow),
+        Cell::new("Merged").fg(Color::Magenta),
+        Cell::new("Branches").fg(Color::Cyan),
+    ]);
+    for (owner, owned) in &owners {
+        let names: Vec<&str> = owned.iter().map(|b| b.name.as_str()).collect();
+        table.add_row(vec![
+            Cell::new(owner),
+            Cell::new(owned.len().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(owned.iter().filter(|b| b.merged).count().to_string())
+                .set_alignment(CellAlignment::Right),
+            Cell::new(names.join("\n")),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+/// Hours as a compact duration: "5h", "3.5d"
+#[allow(clippy::cast_precision_loss)]
+fn format_hours(hours: usize) -> String {
+    if hours < 48 {
+        format!("{hours}h")
+    } else {
+        format!("{:.1}d", hours as f64 / 24.0)
+    }
+}
+
+/// Merged branches and their lead-time distributions per team and period
+pub fn display_merges(
+    main_branch: &str,
+    merges: &[MergeInfo],
+    teams: &[LeadTimeStats],
+    periods: &[LeadTimeStats],
+    top: usize,
+) {
+    println!("\n{}\n", title("🔀 Merge Lead Time"));
+    println!(
+        "{}: {}",
+        label("Branches merged into"),
+        format!("{main_branch} ({})", merges.len()).yellow()
+    );
+    println!("{}", "Lead time runs from a branch's first commit to its merge".dimmed());
+
+    println!("\n{}", heading("By Team:"));
+    display_lead_time_table("Team", teams);
+    println!("{}", heading("By Period:"));
+    display_lead_time_table("Period", periods);
+
+    println!("{}", heading("Recent Merges:"));
+    if merges.is_empty() {
+        println!("{}", "None".dimmed());
+        return;
+    }
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Merge").fg(Color::Cyan),
+        Cell::new("PR").fg(Color::Cyan),
+        Cell::new("Branch").fg(Color::Cyan),
+        Cell::new("Owner").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Authors").fg(Color::Green),
+        Cell::new("Churn").fg(Color::Yellow),
+        Cell::new("Lead Time").fg(Color::Magenta),
+    ]);
+    for merge in merges.iter().take(top) {
+        table.add_row(vec![
+            Cell::new(format!("{} {}", merge.merged_at.format("%Y-%m-%d"), merge.hash)),
+            Cell::new(merge.pr.map_or_else(String::new, |pr| format!("#{pr}"))),
+            Cell::new(merge.branch.as_deref().unwrap_or("")),
+            Cell::new(&merge.owner),
+            Cell::new(merge.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(merge.authors.join(", ")),
+            Cell::new(merge.churn.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format_hours(merge.lead_time)).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+fn display_lead_time_table(label: &str, stats: &[LeadTimeStats]) {
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new(label).fg(Color::Cyan),
+        Cell::new("Merges").fg(Color::Green),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Churn").fg(Color::Yellow),
+        Cell::new("Median").fg(Color::Magenta),
+        Cell::new("P75").fg(Color::Magenta),
+        Cell::new("P90").fg(Color::Red),
+    ]);
+    for stat in stats {
+        table.add_row(vec![
+            Cell::new(&stat.label),
+            Cell::new(stat.merges().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.churn.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format_hours(stat.median())).set_alignment(CellAlignment::Right),
+            Cell::new(format_hours(stat.p75())).set_alignment(CellAlignment::Right),
+            Cell::new(format_hours(stat.p90())).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+}
+
+/// Pull requests per contributor, their size distribution, throughput and the latest PRs
+pub fn display_pull_requests(report: &PullRequestReport, interval: Interval, top: usize) {
+    println!("\n{}\n", title("🚀 Pull Requests"));
+    let squashed = report.pull_requests.iter().filter(|pr| pr.squashed).count();
+    println!(
+        "{}: {}",
+        label("Delivered"),
+        format!(
+            "{} PRs ({squashed} squash-merged, {} merge commits)",
+            report.pull_requests.len(),
+            report.pull_requests.len() - squashed
+        )
+        .yellow()
+    );
+
+    println!("\n{}", heading("By Contributor:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("PRs").fg(Color::Green),
+        Cell::new("Co-authored").fg(Color::Green),
+        Cell::new("Median Lines").fg(Color::Yellow),
+        Cell::new("P90 Lines").fg(Color::Yellow),
+    ]);
+    for stat in &report.contributors {
+        table.add_row(vec![
+            Cell::new(format!("{} <{}>", stat.name, stat.email)),
+            Cell::new(stat.authored.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.co_authored.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.median_lines().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.p90_lines().to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+
+    println!("{}", heading("Lines Changed per PR:"));
+    display_size_histogram(report.pull_requests.iter().map(|pr| pr.lines));
+
+    println!("{}", heading("Throughput:"));
+    let mut throughput: BTreeMap<String, usize> = BTreeMap::new();
+    for pr in &report.pull_requests {
+        *throughput.entry(interval.bucket(pr.merged_at)).or_insert(0) += 1;
+    }
+    let max_count = throughput.values().copied().max().unwrap_or(0).max(1);
+    begin_block();
+    for (period, count) in &throughput {
+        let bar = "█".repeat(count * 50 / max_count);
+        println!("{period} │ {} {}", bar.green(), format!("({count})").dimmed());
+    }
+    end_block();
+    println!();
+
+    println!("{}", heading("Recent Pull Requests:"));
+    if report.pull_requests.is_empty() {
+        println!("{}", "None".dimmed());
+        return;
+    }
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("PR").fg(Color::Cyan),
+        Cell::new("Commit").fg(Color::Cyan),
+        Cell::new("Merged").fg(Color::Cyan),
+        Cell::new("Author").fg(Color::Cyan),
+        Cell::new("Co-authors").fg(Color::Cyan),
+        Cell::new("Lines").fg(Color::Yellow),
+        Cell::new("Files").fg(Color::Yellow),
+        Cell::new("Title").fg(Color::Cyan),
+    ]);
+    for pr in report.pull_requests.iter().take(top) {
+        let co_authors: Vec<&str> = pr.co_authors.iter().map(|(name, _)| name.as_str()).collect();
+        table.add_row(vec![
+            Cell::new(format!("#{}", pr.number)),
+            Cell::new(&pr.hash),
+            Cell::new(format!(
+                "{} ({})",
+                pr.merged_at.format("%Y-%m-%d"),
+                if pr.squashed { "squash" } else { "merge" }
+            )),
+            Cell::new(&pr.author),
+            Cell::new(co_authors.join(", ")),
+            Cell::new(pr.lines.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(pr.files.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(&pr.title),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+/// Deployment frequency, lead time for changes and hotfix rate, overall and per period
+#[allow(clippy::cast_precision_loss)]
+pub fn display_delivery(
+    pattern: &str,
+    releases: &[Release],
+    days: i64,
+    total: &DeliveryStats,
+    periods: &[DeliveryStats],
+    top: usize,
+) {
+    println!("\n{}\n", title("🚢 Delivery"));
+    if releases.is_empty() {
+        println!("{}", format!("No release tags matching '{pattern}'").dimmed());
+        return;
+    }
+
+    println!("{}: {}", label("Releases"), format!("{} ({pattern})", total.releases).yellow());
+    println!(
+        "{}: {}",
+        label("Deployment frequency"),
+        format!("{:.1} per week", releases_per_week(releases, days, Utc::now())).yellow()
+    );
+    println!(
+        "{}: {}",
+        label("Lead time for changes"),
+        format!(
+            "median {}, p90 {}",
+            format_hours(total.median_lead_time()),
+            format_hours(total.p90_lead_time())
+        )
+        .yellow()
+    );
+    println!(
+        "{}: {}",
+        label("Change failure rate"),
+        format!("{}% ({} hotfix releases)", total.change_failure_rate(), total.hotfixes).yellow()
+    );
+
+    println!("\n{}", heading("By Period:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Period").fg(Color::Cyan),
+        Cell::new("Releases").fg(Color::Green),
+        Cell::new("Hotfixes").fg(Color::Red),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Churn").fg(Color::Yellow),
+        Cell::new("Median Lead Time").fg(Color::Magenta),
+        Cell::new("P90 Lead Time").fg(Color::Magenta),
+    ]);
+    for stat in periods {
+        table.add_row(vec![
+            Cell::new(&stat.label),
+            Cell::new(stat.releases.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.hotfixes.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.churn.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format_hours(stat.median_lead_time())).set_alignment(CellAlignment::Right),
+            Cell::new(format_hours(stat.p90_lead_time())).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+
+    println!("{}", heading("Releases:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Tag").fg(Color::Cyan),
+        Cell::new("Released").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Churn").fg(Color::Yellow),
+        Cell::new("Authors").fg(Color::Cyan),
+        Cell::new("Median Lead Time").fg(Color::Magenta),
+        Cell::new("Hotfix").fg(Color::Red),
+    ]);
+    for release in releases.iter().take(top) {
+        table.add_row(vec![
+            Cell::new(&release.tag),
+            Cell::new(format!("{} {}", release.released_at.format("%Y-%m-%d"), release.hash)),
+            Cell::new(release.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(release.churn.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(release.authors.join(", ")),
+            Cell::new(format_hours(median(&release.lead_times)))
+                .set_alignment(CellAlignment::Right),
+            Cell::new(release.hotfix.map_or_else(String::new, |reason| reason.to_string())),
+        ]);
+    }
+    println!("{}", render(&table));
+}
+
+/// Thank-you summary of one release: contributors, new faces, hot spots and commit types
+#[allow(clippy::cast_possible_wrap)]
+pub fn display_release(summary: &ReleaseSummary, top: usize) {
+    let range = summary.from.as_ref().map_or_else(
+        || format!("up to {}", summary.tag),
+        |from| format!("{from}..{}", summary.tag),
+    );
+    println!("\n{}\n", title(&format!("🎁 Release {}", summary.tag)));
+    println!(
+        "{}: {}",
+        label("Range"),
+        format!("{range} ({})", summary.date.format("%Y-%m-%d")).yellow()
+    );
+    println!(
+        "{}: {}",
+        label("Commits"),
+        format!("{} by {} contributors", summary.commits(), summary.contributors.len()).yellow()
+    );
+    println!("{}: {}", label("Churn"), format!("{} lines", summary.churn()).yellow());
+
+    println!("\n{}", heading("Contributors:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Additions").fg(Color::Green),
+        Cell::new("Deletions").fg(Color::Red),
+        Cell::new("Files").fg(Color::Yellow),
+        Cell::new("Net").fg(Color::Magenta),
+    ]);
+    for stat in &summary.contributors {
+        let net = stat.additions as i64 - stat.deletions as i64;
+        let net_str = if net >= 0 {
+            format!("+{net}").green().to_string()
+        } else {
+            format!("{net}").red().to_string()
+        };
+        let mut contributor = format!("{} <{}>", stat.name, stat.email);
+        if summary.first_time.contains(&format!("{}|{}", stat.name, stat.email)) {
+            contributor.push_str(" 🎉");
+        }
+        table.add_row(vec![
+            Cell::new(contributor),
+            Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.additions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.deletions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.files_changed.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(net_str).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+
+    println!("{}", heading("First-time Contributors:"));
+    let mut first_timers: Vec<&ContributorStats> = summary
+        .contributors
+        .iter()
+        .filter(|s| summary.first_time.contains(&format!("{}|{}", s.name, s.email)))
+        .collect();
+    first_timers.sort_by(|a, b| a.name.cmp(&b.name));
+    begin_block();
+    if first_timers.is_empty() {
+        println!("  {}", "None".dimmed());
+    }
+    for stat in first_timers {
+        println!("  🎉 {} <{}>", stat.name, stat.email);
+    }
+    end_block();
+
+    println!("\n{}", heading("Commit Types:"));
+    display_type_shares(&summary.types);
+
+    println!("{}", heading("By Directory:"));
+    display_release_paths("Directory", &summary.directories, top);
+    println!("{}", heading("Most Changed Files:"));
+    display_release_paths("Path", &summary.files, top);
+}
+
+fn display_release_paths(label: &str, paths: &[FileStats], top: usize) {
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new(label).fg(Color::Cyan),
+        Cell::new("Changes").fg(Color::Green),
+        Cell::new("Added").fg(Color::Green),
+        Cell::new("Deleted").fg(Color::Red),
+        Cell::new("Churn").fg(Color::Magenta),
+        Cell::new("Contributors").fg(Color::Yellow),
+    ]);
+    for path in paths.iter().take(top) {
+        table.add_row(vec![
+            Cell::new(&path.path),
+            Cell::new(path.changes.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(path.additions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(path.deletions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(path.churn().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(path.contributors.join(", ")),
+        ]);
+    }
+    println!("{}\n", render(&table));
+}
+
+/// New joiners' ramp-up curves next to the team's historical median
+pub fn display_onboarding(report: &OnboardingReport, weeks: usize, top: usize) {
+    println!("\n{}\n", title("🌱 Onboarding"));
+    println!("{}: {}", label("Ramp-up window"), format!("{weeks} weeks").yellow());
+    if report.joiners.is_empty() {
+        println!("\n{}", "No new contributors in this period".dimmed());
+        return;
+    }
+    let joiners: Vec<&RampUp> = report.joiners.iter().take(top).collect();
+
+    println!("\n{}", heading("New Joiners:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("First Commit").fg(Color::Cyan),
+        Cell::new("Weeks").fg(Color::Yellow),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("vs Team").fg(Color::Magenta),
+        Cell::new("Churn").fg(Color::Yellow),
+        Cell::new("Files").fg(Color::Yellow),
+        Cell::new("Directories").fg(Color::Yellow),
+    ]);
+    for ramp in &joiners {
+        // Compare over the same number of weeks the joiner has had
+        let expected: usize =
+            report.baseline.iter().take(ramp.weeks.len()).map(|w| w.commits).sum();
+        let versus = (ramp.commits() * 100)
+            .checked_div(expected)
+            .map_or_else(|| "-".to_string(), |percent| format!("{percent}%"));
+        table.add_row(vec![
+            Cell::new(format!("{} <{}>", ramp.name, ramp.email)),
+            Cell::new(ramp.first_commit.format("%Y-%m-%d")),
+            Cell::new(ramp.weeks.len().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(ramp.commits().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(versus).set_alignment(CellAlignment::Right),
+            Cell::new(ramp.churn().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(ramp.breadth().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(ramp.directories.len().to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+
+    println!("{}", heading("Commits per Week:"));
+    display_ramp_table(&report.baseline, &joiners, |w| w.commits);
+    println!("{}", heading("Churn per Week:"));
+    display_ramp_table(&report.baseline, &joiners, |w| w.churn);
+    println!("{}", heading("Files Touched So Far:"));
+    display_ramp_table(&report.baseline, &joiners, |w| w.breadth);
+
+    println!("{}", heading("Days to First Change by Directory:"));
+    let mut table = new_table();
+    let mut header = vec![
+        Cell::new("Directory").fg(Color::Cyan),
+        Cell::new("Team Median").fg(Color::Magenta),
+    ];
+    header.extend(joiners.iter().map(|ramp| Cell::new(&ramp.name).fg(Color::Green)));
+    table.set_header(header);
+    for (dir, median_days) in &report.directories {
+        let mut row = vec![
+            Cell::new(dir),
+            Cell::new(median_days.to_string()).set_alignment(CellAlignment::Right),
+        ];
+        row.extend(joiners.iter().map(|ramp| {
+            let days = ramp.directories.get(dir).map_or_else(String::new, ToString::to_string);
+            Cell::new(days).set_alignment(CellAlignment::Right)
+        }));
+        table.add_row(row);
+    }
+    println!("{}", render(&table));
+}
+
+/// One row per joiner with a value per week, under the team median; blank past today
+fn display_ramp_table(
+    baseline: &[RampWeek],
+    joiners: &[&RampUp],
+    value: impl Fn(&RampWeek) -> usize,
+) {
+    let mut table = new_table();
+    let mut header = vec![Cell::new("Contributor").fg(Color::Cyan)];
+    header.extend((1..=baseline.len()).map(|week| Cell::new(format!("W{week}")).fg(Color::Yellow)));
+    table.set_header(header);
+
+    let mut row = vec![Cell::new("Team median").fg(Color::Magenta)];
+    row.extend(
+        baseline
+            .iter()
+            .map(|week| Cell::new(value(week).to_string()).set_alignment(CellAlignment::Right)),
+    );
+    table.add_row(row);
+    for ramp in joiners {
+        let mut row = vec![Cell::new(&ramp.name)];
+        row.extend((0..baseline.len()).map(|idx| {
+            let cell = ramp.weeks.get(idx).map_or_else(String::new, |week| value(week).to_string());
+            Cell::new(cell).set_alignment(CellAlignment::Right)
+        }));
+        table.add_row(row);
+    }
+    println!("{}\n", render(&table));
+}
+
+/// Contributor statuses, departed owners of code at HEAD and headcount over time
+pub fn display_roster(report: &RosterReport, windows: ActivityWindows, min_lines: usize) {
+    println!("\n{}\n", title("🧭 Roster"));
+    println!(
+        "{}: {}",
+        label("Windows"),
+        format!(
+            "active {}d, ramping {}d, departed {}d",
+            windows.active, windows.ramping, windows.departed
+        )
+        .yellow()
+    );
+    let counts: Vec<String> = [
+        ActivityStatus::Active,
+        ActivityStatus::Ramping,
+        ActivityStatus::Fading,
+        ActivityStatus::Departed,
+    ]
+    .into_iter()
+    .map(|status| {
+        let count = report.entries.iter().filter(|e| e.status == status).count();
+        format!("{count} {status}")
+    })
+    .collect();
+    println!("{}: {}", label("Contributors"), counts.join(", ").yellow());
+
+    println!("\n{}", heading("By Contributor:"));
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Contributor").fg(Color::Cyan),
+        Cell::new("Status").fg(Color::Cyan),
+        Cell::new("First Seen").fg(Color::Cyan),
+        Cell::new("Last Seen").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new(format!("Last {}d", windows.active)).fg(Color::Green),
+        Cell::new("Active Days").fg(Color::Yellow),
+        Cell::new("Active Weeks").fg(Color::Yellow),
+    ]);
+    for entry in &report.entries {
+        let stat = &entry.stats;
+        let color = match entry.status {
+            ActivityStatus::Active => Color::Green,
+            ActivityStatus::Ramping => Color::Cyan,
+            ActivityStatus::Fading => Color::Yellow,
+            ActivityStatus::Departed => Color::Red,
+        };
+        table.add_row(vec![
+            Cell::new(format!("{} <{}>", stat.name, stat.email)),
+            Cell::new(entry.status.to_string()).fg(color),
+            Cell::new(format_date(stat.first_commit)),
+            Cell::new(format_date(stat.last_commit)),
+            Cell::new(stat.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(entry.recent_commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.active_days.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(stat.active_weeks.to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+
+    println!("{}", heading(&format!("Departed, Still Owning {min_lines}+ Lines at HEAD:")));
+    let mut owners: Vec<&RosterEntry> = report
+        .entries
+        .iter()
+        .filter(|e| e.status == ActivityStatus::Departed && e.owned_lines >= min_lines)
+        .collect();
+    owners.sort_by_key(|e| Reverse(e.owned_lines));
+    if owners.is_empty() {
+        println!("  {}\n", "None".dimmed());
+    } else {
+        let mut table = new_table();
+        table.set_header(vec![
+            Cell::new("Contributor").fg(Color::Cyan),
+            Cell::new("Last Seen").fg(Color::Cyan),
+            Cell::new("Lines").fg(Color::Red),
+            Cell::new("Share").fg(Color::Red),
+        ]);
+        for entry in owners {
+            let share = (entry.owned_lines * 100).checked_div(report.total_lines).unwrap_or(0);
+            table.add_row(vec![
+                Cell::new(format!("{} <{}>", entry.stats.name, entry.stats.email)),
+                Cell::new(format_date(entry.stats.last_commit)),
+                Cell::new(entry.owned_lines.to_string()).set_alignment(CellAlignment::Right),
+                Cell::new(format!("{share}%")).set_alignment(CellAlignment::Right),
+            ]);
+        }
+        println!("{}\n", render(&table));
+    }
+
+    println!("{}", heading("Headcount from Commits:"));
+    let peak = report.headcount.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    begin_block();
+    for (period, count) in &report.headcount {
+        let bar = "█".repeat((count * 30).checked_div(peak).unwrap_or(0));
+        println!("{period} │ {} {}", bar.green(), format!("({count})").dimmed());
+    }
+    end_block();
+}
+
+pub fn display_languages(languages: &[LanguageStats]) {
+    println!("\n{}\n", title("🗣  Language Breakdown"));
+
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Language").fg(Color::Cyan),
+        Cell::new("Files").fg(Color::Yellow),
+        Cell::new("LOC").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Added").fg(Color::Green),
+        Cell::new("Deleted").fg(Color::Red),
+        Cell::new("Churn").fg(Color::Magenta),
+        Cell::new("Contributors").fg(Color::Yellow),
+    ]);
+
+    for language in languages {
+        table.add_row(vec![
+            Cell::new(&language.language),
+            Cell::new(language.files.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(language.lines.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(language.commits.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(language.additions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(language.deletions.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(language.churn().to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(language.contributors.len().to_string()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+
+    println!("{}", render(&table));
+}
+
+/// Commit type shares overall and over time, plus breakdowns by contributor and by team
+/// when those are given (`--by-type`)
+pub fn display_commit_types(
+    contributors: &[TypeBreakdown],
+    teams: &[TypeBreakdown],
+    periods: &[TypeBreakdown],
+) {
+    println!("\n{}\n", title("🏷  Commits by Type"));
+
+    let mut overall = TypeBreakdown::default();
+    for period in periods {
+        overall.absorb(period);
+    }
+    let total = overall.total();
+    let percent = (overall.conventional * 100).checked_div(total).unwrap_or(0);
+    println!(
+        "{}: {}",
+        label("Conventional Commits"),
+        format!("{} of {total} commits ({percent}%)", overall.conventional).yellow()
+    );
+
+    println!("\n{}", heading("Overall:"));
+    display_type_shares(&overall);
+
+    // Only show types that occur at all
+    let kinds: Vec<CommitType> =
+        CommitType::ALL.into_iter().filter(|kind| overall.count(*kind) > 0).collect();
+
+    if !contributors.is_empty() {
+        println!("{}", heading("By Contributor:"));
+        display_type_table("Contributor", contributors, &kinds);
+    }
+    if !teams.is_empty() {
+        println!("{}", heading("By Team:"));
+        display_type_table("Team", teams, &kinds);
+    }
+    println!("{}", heading("Over Time:"));
+    display_type_table("Period", periods, &kinds);
+}
+
+/// Commits and share of the total per type, leaving out types that don't occur
+fn display_type_shares(types: &TypeBreakdown) {
+    let total = types.total();
+    let mut table = new_table();
+    table.set_header(vec![
+        Cell::new("Type").fg(Color::Cyan),
+        Cell::new("Commits").fg(Color::Green),
+        Cell::new("Share").fg(Color::Yellow),
+    ]);
+    for kind in CommitType::ALL {
+        let count = types.count(kind);
+        if count == 0 {
+            continue;
+        }
+        let percent = (count * 100).checked_div(total).unwrap_or(0);
+        table.add_row(vec![
+            Cell::new(kind.title()),
+            Cell::new(count.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format!("{percent}%")).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}\n", render(&table));
+}
+
+fn display_type_table(label: &str, breakdowns: &[TypeBreakdown], kinds: &[CommitType]) {
+    let mut table = new_table();
+
+    let mut header = vec![
+        Cell::new(label).fg(Color::Cyan),
+        Cell::new("Total").fg(Color::Green),
+    ];
+    header.extend(kinds.iter().map(|kind| Cell::new(kind.as_str()).fg(Color::Yellow)));
+    header.push(Cell::new("Fix %").fg(Color::Red));
+    table.set_header(header);
+
+    for breakdown in breakdowns {
+        let total = breakdown.total();
+        let fix_percent = (breakdown.count(CommitType::Fix) * 100).checked_div(total).unwrap_or(0);
+
+        let mut row = vec![
+            Cell::new(&breakdown.label),
+            Cell::new(total.to_string()).set_alignment(CellAlignment::Right),
+        ];
+        row.extend(kinds.iter().map(|kind| {
+            Cell::new(breakdown.count(*kind).to_string()).set_alignment(CellAlignment::Right)
+        }));
+        row.push(Cell::new(format!("{fix_percent}%")).set_alignment(CellAlignment::Right));
+        table.add_row(row);
+    }
+
+    println!("{}\n", render(&table));
 }
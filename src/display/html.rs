@@ -0,0 +1,270 @@
+use super::{
+    format::{format_date, format_effort},
+    svg::{bar_chart, calendar, contributor_share, hour_bars, pie_chart, trend_bars, weekday_bars},
+    ReportData,
+};
+use crate::stats::{ContributorStats, FileStats, PeriodActivity};
+use chrono::Local;
+
+const STYLE: &str = r"
+body { font-family: -apple-system, 'Segoe UI', Roboto, 'Hiragino Sans', sans-serif; color: #111827; background: #f9fafb; margin: 0; }
+main { max-width: 1080px; margin: 0 auto; padding: 32px 24px; }
+header { margin-bottom: 24px; }
+h1 { font-size: 28px; margin: 0 0 4px; }
+h2 { font-size: 18px; margin: 0 0 12px; }
+.meta { color: #6b7280; font-size: 14px; }
+.cards { display: grid; grid-template-columns: repeat(auto-fit, minmax(150px, 1fr)); gap: 12px; margin-bottom: 24px; }
+.card, section { background: #fff; border: 1px solid #e5e7eb; border-radius: 8px; }
+.card { padding: 14px 16px; }
+.card .label { color: #6b7280; font-size: 12px; text-transform: uppercase; letter-spacing: .04em; }
+.card .value { font-size: 24px; font-weight: 600; margin-top: 4px; }
+section { padding: 20px; margin-bottom: 24px; }
+.charts { display: grid; grid-template-columns: repeat(auto-fit, minmax(420px, 1fr)); gap: 24px; }
+svg { width: 100%; height: auto; }
+table { width: 100%; border-collapse: collapse; font-size: 14px; }
+th, td { padding: 6px 10px; border-bottom: 1px solid #f3f4f6; text-align: left; }
+th { color: #6b7280; font-weight: 600; font-size: 12px; text-transform: uppercase; }
+td.num, th.num { text-align: right; font-variant-numeric: tabular-nums; }
+.add { color: #16a34a; } .del { color: #dc2626; }
+.empty { color: #9ca3af; }
+footer { color: #9ca3af; font-size: 12px; text-align: center; }
+@media print { body { background: #fff; } section, .card { break-inside: avoid; } }
+";
+
+/// Escape text for use in HTML and SVG content and attributes
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn card(label: &str, value: &str) -> String {
+    format!(
+        r#"<div class="card"><div class="label">{label}</div><div class="value">{}</div></div>"#,
+        escape(value)
+    )
+}
+
+fn table(headers: &[&str], numeric_from: usize, rows: &[Vec<String>]) -> String {
+    if rows.is_empty() {
+        return r#"<p class="empty">None</p>"#.to_string();
+    }
+    let head: Vec<String> = headers
+        .iter()
+        .enumerate()
+        .map(|(idx, header)| {
+            let class = if idx >= numeric_from {
+                r#" class="num""#
+            } else {
+                ""
+            };
+            format!("<th{class}>{header}</th>")
+        })
+        .collect();
+    let body: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(idx, cell)| {
+                    let class = if idx >= numeric_from {
+                        r#" class="num""#
+                    } else {
+                        ""
+                    };
+                    format!("<td{class}>{cell}</td>")
+                })
+                .collect();
+            format!("<tr>{}</tr>", cells.join(""))
+        })
+        .collect();
+    format!(
+        "<table><thead><tr>{}</tr></thead><tbody>{}</tbody></table>",
+        head.join(""),
+        body.join("\n")
+    )
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn contributor_rows(stats: &[ContributorStats]) -> Vec<Vec<String>> {
+    stats
+        .iter()
+        .map(|stat| {
+            let net = stat.additions as i64 - stat.deletions as i64;
+            let net_class = if net >= 0 { "add" } else { "del" };
+            vec![
+                format!(
+                    "{} <span class=\"meta\">&lt;{}&gt;</span>",
+                    escape(&stat.name),
+                    escape(&stat.email)
+                ),
+                format_date(stat.first_commit),
+                format_date(stat.last_commit),
+                stat.commits.to_string(),
+                stat.prs.to_string(),
+                format!(r#"<span class="add">+{}</span>"#, stat.additions),
+                format!(r#"<span class="del">-{}</span>"#, stat.deletions),
+                format!(r#"<span class="{net_class}">{net:+}</span>"#),
+                stat.files_changed.to_string(),
+                stat.active_days.to_string(),
+                format_effort(stat.effort_minutes),
+            ]
+        })
+        .collect()
+}
+
+fn file_rows(files: &[FileStats], top: usize) -> Vec<Vec<String>> {
+    files
+        .iter()
+        .take(top)
+        .enumerate()
+        .map(|(idx, file)| {
+            vec![
+                (idx + 1).to_string(),
+                format!("<code>{}</code>", escape(&file.path)),
+                file.changes.to_string(),
+                format!(r#"<span class="add">+{}</span>"#, file.additions),
+                format!(r#"<span class="del">-{}</span>"#, file.deletions),
+                file.churn().to_string(),
+                file.contributors.len().to_string(),
+            ]
+        })
+        .collect()
+}
+
+fn summary_cards(stats: &[ContributorStats]) -> String {
+    let commits: usize = stats.iter().map(|s| s.commits).sum();
+    let additions: usize = stats.iter().map(|s| s.additions).sum();
+    let deletions: usize = stats.iter().map(|s| s.deletions).sum();
+    let active_days: usize = stats.iter().map(|s| s.active_days).sum();
+    let effort: usize = stats.iter().map(|s| s.effort_minutes).sum();
+    let net = if additions >= deletions {
+        format!("+{}", additions - deletions)
+    } else {
+        format!("-{}", deletions - additions)
+    };
+    [
+        card("Contributors", &stats.len().to_string()),
+        card("Commits", &commits.to_string()),
+        card("Lines Added", &format!("+{additions}")),
+        card("Lines Deleted", &format!("-{deletions}")),
+        card("Net Change", &net),
+        card("Active Days", &active_days.to_string()),
+        card("Estimated Effort", &format_effort(effort)),
+    ]
+    .join("")
+}
+
+fn trends_section(trends: &[PeriodActivity]) -> String {
+    if trends.is_empty() {
+        return r#"<p class="empty">None</p>"#.to_string();
+    }
+    let rows: Vec<Vec<String>> = trends
+        .iter()
+        .rev()
+        .map(|t| {
+            vec![
+                t.label.clone(),
+                t.commits.to_string(),
+                t.churn.to_string(),
+                t.authors.to_string(),
+            ]
+        })
+        .collect();
+    format!(
+        "{}\n{}",
+        bar_chart(&trend_bars(trends), "#6366f1"),
+        table(&["Period", "Commits", "Churn", "Authors"], 1, &rows)
+    )
+}
+
+/// A complete HTML document with embedded CSS and inline SVG charts, no scripts or external assets
+pub fn render_html_report(report: &ReportData) -> String {
+    let contributors = table(
+        &[
+            "Contributor",
+            "First Commit",
+            "Last Commit",
+            "Commits",
+            "PRs",
+            "Additions",
+            "Deletions",
+            "Net",
+            "Files",
+            "Active Days",
+            "Effort",
+        ],
+        3,
+        &contributor_rows(&report.contributors),
+    );
+    let files = table(
+        &[
+            "Rank",
+            "Path",
+            "Changes",
+            "Added",
+            "Deleted",
+            "Churn",
+            "Contributors",
+        ],
+        2,
+        &file_rows(&report.files, report.top),
+    );
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<main>
+<header>
+<h1>{title}</h1>
+<div class="meta">{period} · {repository}</div>
+</header>
+<div class="cards">{cards}</div>
+<div class="charts">
+<section><h2>Commits by Hour</h2>{hour_chart}</section>
+<section><h2>Commits by Day of Week</h2>{day_chart}</section>
+</div>
+<section><h2>Contribution Calendar</h2>{calendar}</section>
+<section><h2>Trends</h2>{trends}</section>
+<section><h2>Contributors</h2>{share_chart}{contributors}</section>
+<section><h2>Most Changed Files</h2>{files}</section>
+<footer>Generated by gtct on {generated}</footer>
+</main>
+</body>
+</html>
+"#,
+        title = escape(&report.title),
+        period = escape(&report.period),
+        repository = escape(&report.repository),
+        cards = summary_cards(&report.contributors),
+        trends = trends_section(&report.trends),
+        hour_chart = bar_chart(&hour_bars(&report.time), "#22c55e"),
+        day_chart = bar_chart(&weekday_bars(&report.time), "#06b6d4"),
+        calendar = calendar(&report.time.daily, Local::now().date_naive()),
+        share_chart = pie_chart(&contributor_share(&report.contributors)),
+        generated = Local::now().format("%Y-%m-%d %H:%M"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape(r#"<a href="x">Tom & 'Jerry'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use super::{
+    format::{format_bytes, format_date, format_effort},
+    svg::{hour_bars, weekday_bars},
+    ReportData,
+};
+use crate::stats::{ContributorStats, GrowthSample};
+use chrono::Local;
+use comfy_table::Table;
+
+/// Widest bar in the text charts, in characters
+const BAR_WIDTH: usize = 40;
+
+/// Escape a table cell so pipes and line breaks don't end it early
+pub(super) fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// GitHub-flavored Markdown table; columns from `numeric_from` on are right-aligned
+pub(super) fn table(headers: &[&str], numeric_from: usize, rows: &[Vec<String>]) -> String {
+    if rows.is_empty() {
+        return "_None_".to_string();
+    }
+    let align: Vec<&str> = (0..headers.len())
+        .map(|idx| if idx >= numeric_from { "---:" } else { "---" })
+        .collect();
+    let mut lines = vec![
+        format!("| {} |", headers.join(" | ")),
+        format!("| {} |", align.join(" | ")),
+    ];
+    lines.extend(rows.iter().map(|row| format!("| {} |", row.join(" | "))));
+    lines.join("\n")
+}
+
+/// A terminal table as a Markdown table, escaping every header and body cell
+pub(super) fn from_table(source: &Table) -> String {
+    let headers: Vec<String> = source
+        .header()
+        .map(|row| row.cell_iter().map(|c| cell(&c.content())).collect())
+        .unwrap_or_default();
+    let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+    let rows: Vec<Vec<String>> = source
+        .row_iter()
+        .map(|row| row.cell_iter().map(|c| cell(&c.content())).collect())
+        .collect();
+    table(&headers, headers.len(), &rows)
+}
+
+/// Horizontal bar chart in a code fence, so the bars line up in any Markdown viewer
+fn text_bars(bars: &[(String, usize)]) -> String {
+    let max = bars.iter().map(|(_, count)| *count).max().unwrap_or(0).max(1);
+    let width = bars.iter().map(|(label, _)| label.chars().count()).max().unwrap_or(0);
+    let lines: Vec<String> = bars
+        .iter()
+        .map(|(label, count)| {
+            let bar = "█".repeat(count * BAR_WIDTH / max);
+            format!("{label:>width$} │ {bar} ({count})")
+        })
+        .collect();
+    format!("```text\n{}\n```", lines.join("\n"))
+}
+
+fn summary_block(stats: &[ContributorStats]) -> String {
+    let commits: usize = stats.iter().map(|s| s.commits).sum();
+    let additions: usize = stats.iter().map(|s| s.additions).sum();
+    let deletions: usize = stats.iter().map(|s| s.deletions).sum();
+    let active_days: usize = stats.iter().map(|s| s.active_days).sum();
+    let sessions: usize = stats.iter().map(|s| s.sessions).sum();
+    let effort: usize = stats.iter().map(|s| s.effort_minutes).sum();
+    let net = if additions >= deletions {
+        format!("+{}", additions - deletions)
+    } else {
+        format!("-{}", deletions - additions)
+    };
+    [
+        format!("- **Contributors**: {}", stats.len()),
+        format!("- **Commits**: {commits}"),
+        format!("- **Lines**: +{additions} / -{deletions} (net {net})"),
+        format!("- **Active Days**: {active_days} person-days"),
+        format!("- **Estimated Effort**: {} in {sessions} sessions", format_effort(effort)),
+    ]
+    .join("\n")
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn contributor_table(stats: &[ContributorStats]) -> String {
+    let rows: Vec<Vec<String>> = stats
+        .iter()
+        .map(|stat| {
+            let net = stat.additions as i64 - stat.deletions as i64;
+            vec![
+                cell(&stat.name),
+                cell(&stat.email),
+                stat.commits.to_string(),
+                stat.prs.to_string(),
+                format!("+{}", stat.additions),
+                format!("-{}", stat.deletions),
+                format!("{net:+}"),
+                stat.files_changed.to_string(),
+                stat.active_days.to_string(),
+                format_effort(stat.effort_minutes),
+                format_date(stat.first_commit),
+                format_date(stat.last_commit),
+            ]
+        })
+        .collect();
+    table(
+        &[
+            "Contributor",
+            "Email",
+            "Commits",
+            "PRs",
+            "Additions",
+            "Deletions",
+            "Net",
+            "Files",
+            "Active Days",
+            "Effort",
+            "First Commit",
+            "Last Commit",
+        ],
+        2,
+        &rows,
+    )
+}
+
+/// A complete report as GitHub-flavored Markdown, for wikis and chat
+pub fn render_markdown_report(report: &ReportData) -> String {
+    let trend_rows: Vec<Vec<String>> = report
+        .trends
+        .iter()
+        .rev()
+        .map(|t| {
+            vec![
+                t.label.clone(),
+                t.commits.to_string(),
+                t.churn.to_string(),
+                t.authors.to_string(),
+            ]
+        })
+        .collect();
+    let file_rows: Vec<Vec<String>> = report
+        .files
+        .iter()
+        .take(report.top)
+        .enumerate()
+        .map(|(idx, file)| {
+            vec![
+                (idx + 1).to_string(),
+                format!("`{}`", cell(&file.path)),
+                file.changes.to_string(),
+                format!("+{}", file.additions),
+                format!("-{}", file.deletions),
+                file.churn().to_string(),
+                file.contributors.len().to_string(),
+            ]
+        })
+        .collect();
+
+    let sections = [
+        format!("# {}", report.title),
+        format!("_{} · `{}`_", report.period, report.repository),
+        format!("## Summary\n\n{}", summary_block(&report.contributors)),
+        format!("## Commits by Hour\n\n{}", text_bars(&hour_bars(&report.time))),
+        format!("## Commits by Day of Week\n\n{}", text_bars(&weekday_bars(&report.time))),
+        format!(
+            "## Trends\n\n{}",
+            table(&["Period", "Commits", "Churn", "Authors"], 1, &trend_rows)
+        ),
+        format!("## Contributors\n\n{}", contributor_table(&report.contributors)),
+        format!(
+            "## Most Changed Files\n\n{}",
+            table(
+                &[
+                    "Rank",
+                    "Path",
+                    "Changes",
+                    "Added",
+                    "Deleted",
+                    "Churn",
+                    "Contributors"
+                ],
+                2,
+                &file_rows
+            )
+        ),
+        format!("_Generated by gtct on {}_", Local::now().format("%Y-%m-%d %H:%M")),
+    ];
+    format!("{}\n", sections.join("\n\n"))
+}
+
+/// Growth samples as a Markdown table
+pub fn render_growth_markdown(samples: &[GrowthSample]) -> String {
+    let rows: Vec<Vec<String>> = samples
+        .iter()
+        .map(|sample| {
+            vec![
+                cell(&sample.label),
+                sample.hash.clone(),
+                sample.date.format("%Y-%m-%d").to_string(),
+                sample.files.to_string(),
+                sample.lines.to_string(),
+                format_bytes(sample.bytes),
+            ]
+        })
+        .collect();
+    let headers = ["Sample", "Commit", "Date", "Files", "Lines", "Objects"];
+    format!("{}\n", table(&headers, 3, &rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let rows = vec![vec![cell("a|b"), "3".to_string()]];
+        assert_eq!(
+            table(&["Name", "Count"], 1, &rows),
+            "| Name | Count |\n| --- | ---: |\n| a\\|b | 3 |"
+        );
+        assert_eq!(table(&["Name"], 1, &[]), "_None_");
+    }
+
+    #[test]
+    fn test_from_table() {
+        let mut source = Table::new();
+        source.set_header(vec!["Branch", "Title"]);
+        source.add_row(vec!["feat/a|b", "first\nsecond"]);
+        assert_eq!(
+            from_table(&source),
+            "| Branch | Title |\n| --- | --- |\n| feat/a\\|b | first second |"
+        );
+    }
+
+    #[test]
+    fn test_text_bars() {
+        let bars = vec![
+            ("Mon".to_string(), 2),
+            ("Tue".to_string(), 1),
+            ("Wed".to_string(), 0),
+        ];
+        let chart = text_bars(&bars);
+        assert!(chart.starts_with("```text\nMon │ "));
+        assert!(chart.contains(&format!("Tue │ {} (1)", "█".repeat(BAR_WIDTH / 2))));
+        assert!(chart.ends_with("Wed │  (0)\n```"));
+    }
+}
@@ -1,5 +1,69 @@
+pub mod changelog;
+pub mod charts;
 pub mod format;
+pub mod html;
+pub mod markdown;
+pub mod svg;
 
+use crate::stats::{ContributorStats, FileStats, PeriodActivity, TimeStats};
+use clap::ValueEnum;
+use std::sync::OnceLock;
+
+pub use changelog::{render_changelog, ChangelogTemplate};
+pub use charts::{growth_chart, report_charts, share_chart, time_charts, ChartOutput};
 pub use format::{
-    display_contributors, display_file_changes, display_summary, display_time_analysis,
+    display_author_profile, display_binaries, display_branches, display_commit_sizes,
+    display_commit_types, display_contributor_groups, display_contributor_languages,
+    display_contributors, display_delivery, display_experts, display_file_changes,
+    display_formatting, display_growth, display_issues, display_languages, display_merges,
+    display_onboarding, display_pull_requests, display_release, display_rework, display_roster,
+    display_summary, display_survival, display_time_analysis,
 };
+pub use html::render_html_report;
+pub use markdown::{render_growth_markdown, render_markdown_report};
+
+/// How command output is rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Plain text
+    #[default]
+    Text,
+    /// GitHub-flavored Markdown
+    Markdown,
+    /// Machine-readable JSON
+    Json,
+    /// Self-contained HTML page with inline SVG charts
+    Html,
+}
+
+static FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// Set the output format for this run (only the first call has an effect)
+///
+/// Markdown also turns off terminal colors, so no escape codes end up in the document.
+pub fn set_output_format(format: OutputFormat) {
+    if FORMAT.set(format).is_ok() && format == OutputFormat::Markdown {
+        colored::control::set_override(false);
+    }
+}
+
+/// Output format for this run (text unless set)
+fn output_format() -> OutputFormat {
+    FORMAT.get().copied().unwrap_or_default()
+}
+
+/// What goes into a `gtct report` document
+#[derive(Debug, Default)]
+pub struct ReportData {
+    pub title: String,
+    /// Analyzed period, e.g. "Last 7 days"
+    pub period: String,
+    pub repository: String,
+    pub contributors: Vec<ContributorStats>,
+    pub time: TimeStats,
+    /// Most changed first; the first `top` are listed
+    pub files: Vec<FileStats>,
+    /// Oldest period first
+    pub trends: Vec<PeriodActivity>,
+    pub top: usize,
+}
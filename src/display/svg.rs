@@ -0,0 +1,274 @@
+use super::html::escape;
+use crate::stats::{ContributorStats, PeriodActivity, TimeStats};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::BTreeMap;
+
+const WIDTH: f64 = 720.0;
+const HEIGHT: f64 = 240.0;
+/// Room for the y-axis values on the left and the labels below
+const LEFT: f64 = 40.0;
+const BOTTOM: f64 = 28.0;
+const TOP: f64 = 16.0;
+/// Past this many bars only every n-th label is drawn so they don't overlap
+const MAX_LABELS: usize = 16;
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Weeks the contribution calendar shows, like GitHub's profile graph
+const CALENDAR_WEEKS: i64 = 53;
+/// Calendar square size, gap included
+const CELL: f64 = 13.0;
+/// Calendar shades from no commits to the busiest days
+const SHADES: [&str; 5] = ["#ebedf0", "#9be9a8", "#40c463", "#30a14e", "#216e39"];
+
+/// Pie slice colors; contributors past the last one are folded into "Others"
+const PALETTE: [&str; 8] = [
+    "#6366f1", "#22c55e", "#f59e0b", "#ef4444", "#06b6d4", "#a855f7", "#ec4899", "#84cc16",
+];
+const OTHERS_COLOR: &str = "#9ca3af";
+
+/// Commits per hour of the day, 00 to 23
+pub fn hour_bars(time: &TimeStats) -> Vec<(String, usize)> {
+    (0..24)
+        .map(|hour| {
+            let count = time.hour_distribution.get(&hour).copied().unwrap_or(0);
+            (format!("{hour:02}"), count)
+        })
+        .collect()
+}
+
+/// Commits per weekday, Monday first
+pub fn weekday_bars(time: &TimeStats) -> Vec<(String, usize)> {
+    (0u32..)
+        .zip(WEEKDAYS)
+        .map(|(idx, day)| {
+            let count = time.day_distribution.get(&idx).copied().unwrap_or(0);
+            (day.to_string(), count)
+        })
+        .collect()
+}
+
+/// Commits per period, oldest first
+pub fn trend_bars(trends: &[PeriodActivity]) -> Vec<(String, usize)> {
+    trends.iter().map(|t| (t.label.clone(), t.commits)).collect()
+}
+
+/// Commits per contributor, most active first
+pub fn contributor_share(stats: &[ContributorStats]) -> Vec<(String, usize)> {
+    let mut slices: Vec<(String, usize)> =
+        stats.iter().map(|s| (s.name.clone(), s.commits)).collect();
+    slices.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    slices
+}
+
+fn svg_document(width: f64, height: f64, body: &str) -> String {
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" role="img">{body}</svg>"#
+    )
+}
+
+/// Vertical bar chart with one bar per `(label, value)`, hover titles and a light grid
+#[allow(clippy::cast_precision_loss)]
+pub fn bar_chart(bars: &[(String, usize)], color: &str) -> String {
+    let max = bars.iter().map(|(_, value)| *value).max().unwrap_or(0).max(1);
+    let plot_width = WIDTH - LEFT;
+    let plot_height = HEIGHT - TOP - BOTTOM;
+    let slot = plot_width / bars.len().max(1) as f64;
+    let label_every = bars.len().saturating_sub(1) / MAX_LABELS + 1;
+
+    let mut parts = Vec::new();
+    let mut ticks = vec![0, max / 2, max];
+    ticks.dedup();
+    for tick in ticks {
+        let y = TOP + plot_height - plot_height * tick as f64 / max as f64;
+        parts.push(format!(
+            r##"<line x1="{LEFT}" y1="{y:.1}" x2="{WIDTH}" y2="{y:.1}" stroke="#e5e7eb"/><text x="{:.1}" y="{:.1}" text-anchor="end" font-size="11" fill="#6b7280">{tick}</text>"##,
+            LEFT - 6.0,
+            y + 4.0
+        ));
+    }
+    for (idx, (label, value)) in bars.iter().enumerate() {
+        let x = LEFT + slot * idx as f64;
+        let height = plot_height * *value as f64 / max as f64;
+        let y = TOP + plot_height - height;
+        let center = x + slot / 2.0;
+        parts.push(format!(
+            r#"<rect x="{:.1}" y="{y:.1}" width="{:.1}" height="{height:.1}" rx="2" fill="{color}"><title>{}: {value}</title></rect>"#,
+            x + slot * 0.1,
+            slot * 0.8,
+            escape(label)
+        ));
+        if idx % label_every == 0 {
+            parts.push(format!(
+                r##"<text x="{center:.1}" y="{:.1}" text-anchor="middle" font-size="11" fill="#6b7280">{}</text>"##,
+                HEIGHT - 8.0,
+                escape(label)
+            ));
+        }
+    }
+
+    svg_document(WIDTH, HEIGHT, &parts.join(""))
+}
+
+/// Shade index for a day's commits: 0 for none, up to 4 for the busiest day
+const fn shade(count: usize, max: usize) -> usize {
+    if count == 0 {
+        0
+    } else {
+        let level = (count * 4 + max - 1) / max;
+        if level > 4 {
+            4
+        } else {
+            level
+        }
+    }
+}
+
+/// Contribution calendar for the year up to `end`: one column per week, Monday on top
+#[allow(clippy::cast_precision_loss, clippy::suboptimal_flops)]
+pub fn calendar(daily: &BTreeMap<NaiveDate, usize>, end: NaiveDate) -> String {
+    let last_monday = end - Duration::days(i64::from(end.weekday().num_days_from_monday()));
+    let first_monday = last_monday - Duration::weeks(CALENDAR_WEEKS - 1);
+    let max = daily
+        .range(first_monday..=end)
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let left = 32.0;
+    let top = 20.0;
+    let width = left + CELL * CALENDAR_WEEKS as f64 + 8.0;
+    let height = top + CELL * 7.0 + 28.0;
+
+    let mut parts = Vec::new();
+    for (row, day) in [(0, "Mon"), (2, "Wed"), (4, "Fri")] {
+        parts.push(format!(
+            r##"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="10" fill="#6b7280">{day}</text>"##,
+            left - 6.0,
+            top + CELL * f64::from(row) + 9.0
+        ));
+    }
+    for week in 0..CALENDAR_WEEKS {
+        let monday = first_monday + Duration::weeks(week);
+        let x = left + CELL * week as f64;
+        // Month names over the first week of each month; the last column has no room
+        if monday.day() <= 7 && week < CALENDAR_WEEKS - 1 {
+            parts.push(format!(
+                r##"<text x="{x:.1}" y="{:.1}" font-size="10" fill="#6b7280">{}</text>"##,
+                top - 6.0,
+                monday.format("%b")
+            ));
+        }
+        for row in 0..7 {
+            let date = monday + Duration::days(row);
+            if date > end {
+                break;
+            }
+            let count = daily.get(&date).copied().unwrap_or(0);
+            parts.push(format!(
+                r#"<rect x="{x:.1}" y="{:.1}" width="11" height="11" rx="2" fill="{}"><title>{date}: {count} commits</title></rect>"#,
+                top + CELL * row as f64,
+                SHADES[shade(count, max)]
+            ));
+        }
+    }
+    let legend_y = top + CELL * 7.0 + 10.0;
+    // Squares end where the calendar does, with "More" tucked before the right edge
+    let legend_x = width - 40.0 - CELL * SHADES.len() as f64;
+    parts.push(format!(
+        r##"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="10" fill="#6b7280">Less</text>"##,
+        legend_x - 6.0,
+        legend_y + 9.0
+    ));
+    for (idx, color) in SHADES.iter().enumerate() {
+        parts.push(format!(
+            r#"<rect x="{:.1}" y="{legend_y:.1}" width="11" height="11" rx="2" fill="{color}"/>"#,
+            legend_x + CELL * idx as f64
+        ));
+    }
+    parts.push(format!(
+        r##"<text x="{:.1}" y="{:.1}" font-size="10" fill="#6b7280">More</text>"##,
+        width - 36.0,
+        legend_y + 9.0
+    ));
+
+    svg_document(width, height, &parts.join(""))
+}
+
+/// Pie chart with a legend; slices past the palette are folded into "Others"
+#[allow(clippy::cast_precision_loss, clippy::suboptimal_flops)]
+pub fn pie_chart(slices: &[(String, usize)]) -> String {
+    let (cx, cy, radius) = (HEIGHT / 2.0, HEIGHT / 2.0, HEIGHT / 2.0 - TOP);
+    let mut shown: Vec<(String, usize, &str)> = slices
+        .iter()
+        .zip(PALETTE)
+        .map(|((label, value), color)| (label.clone(), *value, color))
+        .collect();
+    if slices.len() > PALETTE.len() {
+        shown.pop();
+        let rest = slices.iter().skip(PALETTE.len() - 1).map(|(_, value)| *value).sum();
+        shown.push(("Others".to_string(), rest, OTHERS_COLOR));
+    }
+    let total: usize = shown.iter().map(|(_, value, _)| *value).sum();
+
+    let mut parts = Vec::new();
+    if total == 0 {
+        parts.push(format!(r##"<circle cx="{cx}" cy="{cy}" r="{radius}" fill="#ebedf0"/>"##));
+    }
+    let mut angle = -std::f64::consts::FRAC_PI_2;
+    for (label, value, color) in shown.iter().filter(|(_, value, _)| total > 0 && *value > 0) {
+        let fraction = *value as f64 / total as f64;
+        let title = format!("<title>{}: {value}</title>", escape(label));
+        if *value == total {
+            parts.push(format!(
+                r#"<circle cx="{cx}" cy="{cy}" r="{radius}" fill="{color}">{title}</circle>"#
+            ));
+            break;
+        }
+        let end = angle + fraction * std::f64::consts::TAU;
+        let large = i32::from(fraction > 0.5);
+        parts.push(format!(
+            r##"<path d="M{cx},{cy} L{:.2},{:.2} A{radius},{radius} 0 {large} 1 {:.2},{:.2} Z" fill="{color}" stroke="#fff" stroke-width="1">{title}</path>"##,
+            cx + radius * angle.cos(),
+            cy + radius * angle.sin(),
+            cx + radius * end.cos(),
+            cy + radius * end.sin()
+        ));
+        angle = end;
+    }
+    for (idx, (label, value, color)) in shown.iter().enumerate() {
+        let y = TOP + 22.0 * idx as f64;
+        let percent = (value * 100).checked_div(total).unwrap_or(0);
+        parts.push(format!(
+            r##"<rect x="{:.1}" y="{y:.1}" width="12" height="12" rx="2" fill="{color}"/><text x="{:.1}" y="{:.1}" font-size="12" fill="#111827">{} — {value} ({percent}%)</text>"##,
+            HEIGHT + 20.0,
+            HEIGHT + 38.0,
+            y + 10.0,
+            escape(label)
+        ));
+    }
+
+    svg_document(WIDTH, HEIGHT, &parts.join(""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shade() {
+        assert_eq!(shade(0, 10), 0);
+        assert_eq!(shade(1, 10), 1);
+        assert_eq!(shade(5, 10), 2);
+        assert_eq!(shade(10, 10), 4);
+    }
+
+    #[test]
+    fn test_pie_chart_folds_others() {
+        let slices: Vec<(String, usize)> = (0..10).map(|idx| (format!("dev{idx}"), 1)).collect();
+        let chart = pie_chart(&slices);
+        assert!(chart.contains("dev6 — 1 (10%)"));
+        assert!(!chart.contains("dev7 —"));
+        assert!(chart.contains("Others — 3 (30%)"));
+    }
+}
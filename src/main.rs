@@ -1,27 +1,50 @@
+//! gtct: team Git statistics and analysis tool.
+
 mod cli;
 mod config;
 mod display;
 mod stats;
+mod tui;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use cli::{Cli, Commands};
 use colored::Colorize;
 use config::Config;
-use display::{display_contributors, display_file_changes, display_summary, display_time_analysis};
+use display::{
+    display_author_profile, display_binaries, display_branches, display_commit_sizes,
+    display_commit_types, display_contributor_groups, display_contributor_languages,
+    display_contributors, display_delivery, display_experts, display_file_changes,
+    display_formatting, display_growth, display_issues, display_languages, display_merges,
+    display_onboarding, display_pull_requests, display_release, display_rework, display_roster,
+    display_summary, display_survival, display_time_analysis, growth_chart, render_changelog,
+    render_growth_markdown, render_html_report, render_markdown_report, report_charts, share_chart,
+    time_charts, ChangelogTemplate, ChartOutput, OutputFormat, ReportData,
+};
 use git2::Repository;
-use stats::{analyze_contributors, analyze_file_changes, analyze_time_distribution};
+use stats::{
+    analyze_activity, analyze_author, analyze_binaries, analyze_branches, analyze_changelog,
+    analyze_commit_sizes, analyze_commit_types, analyze_contributor_groups,
+    analyze_contributor_languages, analyze_contributors, analyze_delivery, analyze_experts,
+    analyze_file_changes, analyze_formatting, analyze_growth, analyze_issues, analyze_languages,
+    analyze_merges, analyze_onboarding, analyze_pull_requests, analyze_release, analyze_rework,
+    analyze_roster, analyze_survival, analyze_time_distribution, parse_range, sort_files,
+    ActivityWindows, AnalysisOptions, BinaryStats, DeliveryStats, GroupBy, Interval, IssueMatcher,
+    LeadTimeStats, PathGrouper, ReworkStats, SampleBy, SessionOptions, SurvivalStats,
+    TypeBreakdown,
+};
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
 
 /// Fetch all remotes to ensure we have the latest data
 fn fetch_all_remotes(repo: &Repository) {
-    print!("{}", "🔄 Fetching latest data from remotes...".cyan());
-    std::io::Write::flush(&mut std::io::stdout()).ok();
+    // Progress goes to stderr so Markdown/JSON output on stdout stays clean
+    eprint!("{}", "🔄 Fetching latest data from remotes...".cyan());
 
-    let remotes = match repo.remotes() {
-        Ok(remotes) => remotes,
-        Err(_) => {
-            println!(" {}", "No remotes found, skipping fetch".yellow());
-            return;
-        }
+    let Ok(remotes) = repo.remotes() else {
+        eprintln!(" {}", "No remotes found, skipping fetch".yellow());
+        return;
     };
 
     let mut fetch_count = 0;
@@ -31,129 +54,655 @@ fn fetch_all_remotes(repo: &Repository) {
         has_remotes = true;
         if let Ok(mut remote) = repo.find_remote(remote_name) {
             // Fetch all refs for this remote
-            let refspec = format!("+refs/heads/*:refs/remotes/{}/*", remote_name);
-            match remote.fetch(&[refspec.as_str()], None, None) {
-                Ok(_) => {
-                    fetch_count += 1;
-                }
-                Err(_) => {
-                    // Silently continue on fetch error (might be network issue)
-                    continue;
-                }
+            let refspec = format!("+refs/heads/*:refs/remotes/{remote_name}/*");
+            // Silently continue on fetch error (might be network issue)
+            if remote.fetch(&[refspec.as_str()], None, None).is_ok() {
+                fetch_count += 1;
             }
         }
     }
 
     if fetch_count > 0 {
-        println!(" {}", format!("✓ Fetched {} remote(s)", fetch_count).green());
+        eprintln!(" {}", format!("✓ Fetched {fetch_count} remote(s)").green());
     } else if has_remotes {
-        println!(" {}", "Already up to date".dimmed());
+        eprintln!(" {}", "Already up to date".dimmed());
     } else {
-        println!(" {}", "No remotes configured".dimmed());
+        eprintln!(" {}", "No remotes configured".dimmed());
     }
 }
 
-fn main() {
-    let cli = Cli::parse();
-    let config = Config::from_env();
+fn run_contributors(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    group_by: GroupBy,
+    languages: bool,
+    charts: Option<&ChartOutput>,
+) -> Result<(), Box<dyn Error>> {
+    // Grouped output doesn't show per-contributor totals, so only walk for them when needed
+    let filtered_stats = if languages || group_by == GroupBy::File || charts.is_some() {
+        config.filter_by_teams(analyze_contributors(repo, days)?, |s| &s.email)
+    } else {
+        Vec::new()
+    };
+    if let Some(output) = charts {
+        output.write(&[share_chart(&filtered_stats)])?;
+    }
 
-    // Use config to determine repo path
-    let repo_path = config.get_repo_path(&cli.repo);
+    if languages {
+        let mix = analyze_contributor_languages(repo, days)?;
+        display_contributor_languages(&filtered_stats, &mix);
+        return Ok(());
+    }
 
-    let repo = match Repository::open(&repo_path) {
-        Ok(repo) => repo,
-        Err(e) => {
-            eprintln!("{} {}", "Error:".red().bold(), e);
-            eprintln!("Make sure you're in a Git repository or specify the path with --repo");
-            std::process::exit(1);
-        }
+    if group_by == GroupBy::File {
+        display_contributors(&filtered_stats);
+        return Ok(());
+    }
+
+    let grouper = PathGrouper::new(repo, group_by)?;
+    let groups: Vec<_> = analyze_contributor_groups(repo, days, &grouper)?
+        .into_iter()
+        .map(|mut group| {
+            group.contributors = config.filter_by_teams(group.contributors, |s| &s.email);
+            group
+        })
+        .filter(|group| !group.contributors.is_empty())
+        .collect();
+    display_contributor_groups(&groups);
+    Ok(())
+}
+
+fn run_commit_types(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    interval: Interval,
+    by_type: bool,
+) -> Result<(), git2::Error> {
+    let report = analyze_commit_types(repo, days, interval)?;
+    let timeline = config.filter_by_teams(report.timeline, |b| &b.email);
+    let periods = TypeBreakdown::rollup(&timeline, |b| b.label.clone());
+
+    let (contributors, teams) = if by_type {
+        let contributors = config.filter_by_teams(report.contributors, |b| &b.email);
+        let mut teams = TypeBreakdown::rollup(&contributors, |b| config.team_of(&b.email));
+        teams.sort_by_key(|b| std::cmp::Reverse(b.total()));
+        (contributors, teams)
+    } else {
+        (Vec::new(), Vec::new())
     };
 
-    // Automatically fetch all remotes before analysis
-    fetch_all_remotes(&repo);
+    display_commit_types(&contributors, &teams, &periods);
+    Ok(())
+}
 
-    let result = match cli.command {
-        Commands::Contributors { days } => {
-            let effective_days = config.get_days(days);
-            match analyze_contributors(&repo, effective_days) {
-                Ok(stats) => {
-                    let filtered_stats = config.filter_by_teams(stats, |s| &s.email);
-                    display_contributors(&filtered_stats);
-                    Ok(())
-                }
-                Err(e) => Err(e),
+fn run_issues(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    matcher: &IssueMatcher,
+    top: usize,
+) -> Result<(), git2::Error> {
+    let mut report = analyze_issues(repo, days, matcher)?;
+    report.contributors = config.filter_by_teams(report.contributors, |c| &c.contributor.email);
+    // Keep tickets that someone on the configured teams worked on
+    report.tickets = report
+        .tickets
+        .into_iter()
+        .filter_map(|mut ticket| {
+            ticket.contributors = config.filter_by_teams(ticket.contributors, String::as_str);
+            (!ticket.contributors.is_empty()).then_some(ticket)
+        })
+        .collect();
+    display_issues(&report, top);
+    Ok(())
+}
+
+fn run_rework(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    window: i64,
+    group_by: GroupBy,
+    interval: Interval,
+) -> Result<(), git2::Error> {
+    let grouper = PathGrouper::new(repo, group_by)?;
+    let mut report = analyze_rework(repo, days, window, &grouper, interval)?;
+    report.contributors = config.filter_by_teams(report.contributors, |s| &s.email);
+    report.reverts = config.filter_by_teams(report.reverts, |r| &r.email);
+
+    let directories = config.filter_by_teams(report.directories, |s| &s.email);
+    let mut directories = ReworkStats::rollup(&directories, |s| s.label.clone());
+    directories.sort_by_key(|s| std::cmp::Reverse(s.rework));
+    report.directories = directories;
+    let timeline = config.filter_by_teams(report.timeline, |s| &s.email);
+    report.timeline = ReworkStats::rollup(&timeline, |s| s.label.clone());
+
+    display_rework(&report, window);
+    Ok(())
+}
+
+fn run_survival(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    group_by: GroupBy,
+    interval: Interval,
+) -> Result<(), git2::Error> {
+    let grouper = PathGrouper::new(repo, group_by)?;
+    let report = analyze_survival(repo, days, &grouper, interval)?;
+    let contributors = config.filter_by_teams(report.contributors, |s| &s.email);
+    let directories = config.filter_by_teams(report.directories, |s| &s.email);
+    let timeline = config.filter_by_teams(report.timeline, |s| &s.email);
+
+    let teams = SurvivalStats::rollup(&contributors, |s| config.team_of(&s.email));
+    let mut directories = SurvivalStats::rollup(&directories, |s| s.label.clone());
+    directories.sort_by_key(|s| std::cmp::Reverse(s.added));
+    let periods = SurvivalStats::rollup(&timeline, |s| s.label.clone());
+
+    display_survival(&contributors, &teams, &directories, &periods);
+    Ok(())
+}
+
+fn run_sizes(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    outlier_lines: usize,
+    top: usize,
+) -> Result<(), git2::Error> {
+    let mut report = analyze_commit_sizes(repo, days, outlier_lines)?;
+    report.contributors = config.filter_by_teams(report.contributors, |s| &s.email);
+    report.outliers = config.filter_by_teams(report.outliers, |o| &o.email);
+    display_commit_sizes(&report, outlier_lines, top);
+    Ok(())
+}
+
+fn run_formatting(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    top: usize,
+) -> Result<(), git2::Error> {
+    let mut report = analyze_formatting(repo, days)?;
+    report.contributors = config.filter_by_teams(report.contributors, |s| &s.email);
+    report.commits = config.filter_by_teams(report.commits, |c| &c.email);
+    display_formatting(&report, top);
+    Ok(())
+}
+
+fn run_binaries(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    top: usize,
+    interval: Interval,
+) -> Result<(), git2::Error> {
+    let report = analyze_binaries(repo, days, interval)?;
+    let contributors = config.filter_by_teams(report.contributors, |s| &s.email);
+    let timeline = config.filter_by_teams(report.timeline, |s| &s.email);
+    let largest = config.filter_by_teams(report.largest, |b| &b.email);
+    let commits = config.filter_by_teams(report.commits, |c| &c.email);
+
+    let teams = BinaryStats::rollup(&contributors, |s| config.team_of(&s.email));
+    let periods = BinaryStats::rollup(&timeline, |s| s.label.clone());
+
+    display_binaries(&contributors, &teams, &periods, &largest, &commits, top);
+    Ok(())
+}
+
+fn run_growth(
+    repo: &Repository,
+    branch: &str,
+    sample_by: SampleBy,
+    every: usize,
+    top: usize,
+    format: OutputFormat,
+    charts: Option<&ChartOutput>,
+) -> Result<(), Box<dyn Error>> {
+    let samples = analyze_growth(repo, branch, sample_by, every)?;
+    if let Some(output) = charts {
+        output.write(&[growth_chart(&samples)])?;
+    }
+    match format {
+        OutputFormat::Markdown => print!("{}", render_growth_markdown(&samples)),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&samples)?),
+        // HTML is turned down by `check_format`
+        OutputFormat::Text | OutputFormat::Html => display_growth(&samples, top),
+    }
+    Ok(())
+}
+
+fn run_branches(
+    repo: &Repository,
+    config: &Config,
+    main_branch: Option<&str>,
+    stale_days: i64,
+) -> Result<(), git2::Error> {
+    let report = analyze_branches(repo, main_branch.or(config.main_branch.as_deref()))?;
+    let branches = config.filter_by_teams(report.branches, |b| &b.email);
+    display_branches(&report.main_branch, &branches, stale_days);
+    Ok(())
+}
+
+fn run_merges(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    main_branch: Option<&str>,
+    interval: Interval,
+    top: usize,
+) -> Result<(), git2::Error> {
+    let report = analyze_merges(repo, days, main_branch.or(config.main_branch.as_deref()))?;
+    let merges = config.filter_by_teams(report.merges, |m| &m.email);
+    let teams = LeadTimeStats::group(&merges, |m| config.team_of(&m.email));
+    let periods = LeadTimeStats::group(&merges, |m| interval.bucket(m.merged_at));
+    display_merges(&report.main_branch, &merges, &teams, &periods, top);
+    Ok(())
+}
+
+fn run_pull_requests(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    interval: Interval,
+    top: usize,
+) -> Result<(), git2::Error> {
+    let mut report = analyze_pull_requests(repo, days)?;
+    report.contributors = config.filter_by_teams(report.contributors, |s| &s.email);
+    report.pull_requests = config.filter_by_teams(report.pull_requests, |pr| &pr.email);
+    display_pull_requests(&report, interval, top);
+    Ok(())
+}
+
+fn run_delivery(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    tags: Option<&str>,
+    interval: Interval,
+    top: usize,
+) -> Result<(), git2::Error> {
+    let pattern = tags.or(config.release_tags.as_deref()).unwrap_or("v*");
+    let mut releases = analyze_delivery(repo, days, pattern)?;
+    let periods = DeliveryStats::group(&releases, |r| interval.bucket(r.released_at));
+    let total = DeliveryStats::group(&releases, |_| String::new()).pop().unwrap_or_default();
+    releases.reverse();
+    display_delivery(pattern, &releases, days, &total, &periods, top);
+    Ok(())
+}
+
+fn run_changelog(
+    repo: &Repository,
+    config: &Config,
+    range: &str,
+    format: OutputFormat,
+    template: Option<PathBuf>,
+    scope_template: Option<String>,
+    entry_template: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let mut changelog_template = ChangelogTemplate::for_format(format);
+    if let Some(path) = template {
+        changelog_template.document = std::fs::read_to_string(path)?;
+    }
+    if let Some(scope) = scope_template {
+        changelog_template.scope = scope;
+    }
+    if let Some(entry) = entry_template {
+        changelog_template.entry = entry;
+    }
+
+    let (from, to) = parse_range(range);
+    let issues = IssueMatcher::new(&config.issue_patterns, config.issue_url.clone())?;
+    let changelog = analyze_changelog(repo, from, to, &issues)?;
+    print!("{}", render_changelog(&changelog, format, &changelog_template)?);
+    Ok(())
+}
+
+fn run_report(
+    repo: &Repository,
+    config: &Config,
+    period: &str,
+    format: OutputFormat,
+    output: Option<&Path>,
+    top: usize,
+    charts: Option<&ChartOutput>,
+) -> Result<(), Box<dyn Error>> {
+    let days = if period == "weekly" { 7 } else { 30 };
+    let effective_days = config.get_days(days);
+    if format == OutputFormat::Text && output.is_some() {
+        return Err("--output is only supported with --format markdown or html".into());
+    }
+
+    // The terminal report has no trends chart, so skip that walk unless something shows it
+    let trends = format != OutputFormat::Text || charts.is_some();
+    let report = collect_report(repo, config, period, effective_days, top, trends)?;
+    if let Some(output) = charts {
+        output.write(&report_charts(&report))?;
+    }
+
+    if format != OutputFormat::Text {
+        let document = if format == OutputFormat::Html {
+            render_html_report(&report)
+        } else {
+            render_markdown_report(&report)
+        };
+        match output {
+            Some(path) => {
+                std::fs::write(path, document)?;
+                eprintln!("{} {}", "✓ Report written to".green(), path.display());
             }
+            None => print!("{document}"),
         }
+        return Ok(());
+    }
+
+    println!("\n{}", format!("=== {} Report ===", period.to_uppercase()).bold().cyan());
+    display_summary(repo, &report.contributors, effective_days);
+    display_contributors(&report.contributors);
+    display_time_analysis(&report.time);
+    display_file_changes(&report.files, 10);
+    Ok(())
+}
+
+/// Everything a report shows, with trends only when `trends` is set
+fn collect_report(
+    repo: &Repository,
+    config: &Config,
+    period: &str,
+    days: i64,
+    top: usize,
+    trends: bool,
+) -> Result<ReportData, git2::Error> {
+    let stats = analyze_contributors(repo, days)?;
+    let interval = if period == "weekly" {
+        Interval::Week
+    } else {
+        Interval::Month
+    };
+
+    let mut title: Vec<char> = period.chars().collect();
+    if let Some(first) = title.first_mut() {
+        *first = first.to_ascii_uppercase();
+    }
+    let repository = repo.workdir().unwrap_or_else(|| repo.path());
+    Ok(ReportData {
+        title: format!("{} Report", title.into_iter().collect::<String>()),
+        period: if days == 0 {
+            "All Time".to_string()
+        } else {
+            format!("Last {days} days")
+        },
+        repository: repository.display().to_string(),
+        contributors: config.filter_by_teams(stats, |s| &s.email),
+        time: analyze_time_distribution(repo, days)?,
+        files: analyze_file_changes(repo, days, &PathGrouper::default())?,
+        // Twelve periods of the report's size, so the latest one can be seen in context
+        trends: if trends {
+            analyze_activity(repo, days * 12, interval)?
+        } else {
+            Vec::new()
+        },
+        top,
+    })
+}
+
+/// Text and Markdown work everywhere; other formats only where a renderer exists
+fn check_format(command: &Commands, format: OutputFormat) -> Result<(), String> {
+    let supported = match command {
+        Commands::Changelog { .. } | Commands::Growth { .. } => format != OutputFormat::Html,
+        Commands::Report { .. } => format != OutputFormat::Json,
+        Commands::Tui { .. } => format == OutputFormat::Text,
+        _ => matches!(format, OutputFormat::Text | OutputFormat::Markdown),
+    };
+    if supported {
+        return Ok(());
+    }
+    let name = format
+        .to_possible_value()
+        .map_or_else(String::new, |v| v.get_name().to_string());
+    Err(format!("--format {name} is not supported by this command"))
+}
+
+/// Only commands with charts in them can write them out
+const fn check_charts(
+    command: &Commands,
+    charts: Option<&ChartOutput>,
+) -> Result<(), &'static str> {
+    match (command, charts) {
+        (
+            Commands::Contributors { .. }
+            | Commands::TimeAnalysis { .. }
+            | Commands::Report { .. }
+            | Commands::Author { .. }
+            | Commands::Growth { .. },
+            _,
+        )
+        | (_, None) => Ok(()),
+        _ => Err("--chart-out is not supported by this command"),
+    }
+}
+
+// One arm per subcommand; the work lives in the run_* helpers
+#[allow(clippy::too_many_lines)]
+fn run(
+    command: Commands,
+    repo: &Repository,
+    config: &Config,
+    outlier_lines: usize,
+    format: OutputFormat,
+    charts: Option<&ChartOutput>,
+) -> Result<(), Box<dyn Error>> {
+    check_format(&command, format)?;
+    check_charts(&command, charts)?;
+    match command {
+        Commands::Contributors {
+            days,
+            group_by,
+            languages,
+        } => run_contributors(repo, config, config.get_days(days), group_by, languages, charts)?,
         Commands::TimeAnalysis { days } => {
-            let effective_days = config.get_days(days);
-            match analyze_time_distribution(&repo, effective_days) {
-                Ok(time_stats) => {
-                    display_time_analysis(&time_stats);
-                    Ok(())
-                }
-                Err(e) => Err(e),
+            let time_stats = analyze_time_distribution(repo, config.get_days(days))?;
+            if let Some(output) = charts {
+                output.write(&time_charts(&time_stats))?;
             }
+            display_time_analysis(&time_stats);
         }
-        Commands::Files { top, days } => {
-            let effective_days = config.get_days(days);
-            match analyze_file_changes(&repo, effective_days) {
-                Ok(files) => {
-                    display_file_changes(&files, top);
-                    Ok(())
-                }
-                Err(e) => Err(e),
-            }
+        Commands::Files {
+            top,
+            days,
+            group_by,
+            sort,
+        } => {
+            let grouper = PathGrouper::new(repo, group_by)?;
+            let mut files = analyze_file_changes(repo, config.get_days(days), &grouper)?;
+            sort_files(&mut files, sort);
+            display_file_changes(&files, top);
         }
         Commands::Summary { days } => {
             let effective_days = config.get_days(days);
-            match analyze_contributors(&repo, effective_days) {
-                Ok(stats) => {
-                    let filtered_stats = config.filter_by_teams(stats, |s| &s.email);
-                    display_summary(&repo, &filtered_stats, effective_days)
+            let stats = analyze_contributors(repo, effective_days)?;
+            let filtered_stats = config.filter_by_teams(stats, |s| &s.email);
+            display_summary(repo, &filtered_stats, effective_days);
+        }
+        Commands::Report {
+            period,
+            output,
+            top,
+        } => run_report(repo, config, &period, format, output.as_deref(), top, charts)?,
+        Commands::Commits {
+            days,
+            by_type,
+            interval,
+        } => {
+            run_commit_types(repo, config, config.get_days(days), interval, by_type)?;
+        }
+        Commands::Changelog {
+            range,
+            template,
+            scope_template,
+            entry_template,
+        } => run_changelog(repo, config, &range, format, template, scope_template, entry_template)?,
+        Commands::Issues { days, top } => {
+            let matcher = IssueMatcher::new(&config.issue_patterns, config.issue_url.clone())?;
+            run_issues(repo, config, config.get_days(days), &matcher, top)?;
+        }
+        Commands::Rework {
+            days,
+            window,
+            group_by,
+            interval,
+        } => run_rework(repo, config, config.get_days(days), window, group_by, interval)?,
+        Commands::Survival {
+            days,
+            group_by,
+            interval,
+        } => run_survival(repo, config, config.get_days(days), group_by, interval)?,
+        Commands::Sizes { days, top } => {
+            run_sizes(repo, config, config.get_days(days), outlier_lines, top)?;
+        }
+        Commands::Formatting { days, top } => {
+            run_formatting(repo, config, config.get_days(days), top)?;
+        }
+        Commands::Binaries {
+            days,
+            top,
+            interval,
+        } => run_binaries(repo, config, config.get_days(days), top, interval)?,
+        Commands::Growth {
+            by,
+            every,
+            branch,
+            top,
+        } => run_growth(repo, &branch, by, every, top, format, charts)?,
+        Commands::Branches { main, stale_days } => {
+            run_branches(repo, config, main.as_deref(), stale_days)?;
+        }
+        Commands::Merges {
+            days,
+            main,
+            interval,
+            top,
+        } => run_merges(repo, config, config.get_days(days), main.as_deref(), interval, top)?,
+        Commands::Prs {
+            days,
+            interval,
+            top,
+        } => run_pull_requests(repo, config, config.get_days(days), interval, top)?,
+        Commands::Delivery {
+            days,
+            tags,
+            interval,
+            top,
+        } => run_delivery(repo, config, config.get_days(days), tags.as_deref(), interval, top)?,
+        Commands::Onboarding { days, weeks, top } => {
+            let mut report = analyze_onboarding(repo, config.get_days(days), weeks)?;
+            report.joiners = config.filter_by_teams(report.joiners, |r| &r.email);
+            display_onboarding(&report, weeks, top);
+        }
+        Commands::Roster {
+            active_days,
+            ramping_days,
+            departed_days,
+            min_lines,
+            interval,
+        } => {
+            let defaults = ActivityWindows::default();
+            let windows = ActivityWindows {
+                active: active_days.or(config.active_days).unwrap_or(defaults.active),
+                ramping: ramping_days.or(config.ramping_days).unwrap_or(defaults.ramping),
+                departed: departed_days.or(config.departed_days).unwrap_or(defaults.departed),
+            };
+            let mut report = analyze_roster(repo, windows, interval)?;
+            report.entries = config.filter_by_teams(report.entries, |e| &e.stats.email);
+            display_roster(&report, windows, min_lines);
+        }
+        Commands::Release { tag, from, top } => {
+            let summary =
+                analyze_release(repo, &tag, from.as_deref(), config.release_tags.as_deref())?;
+            display_release(&summary, top);
+        }
+        Commands::Languages { days } => {
+            let languages = analyze_languages(repo, config.get_days(days))?;
+            display_languages(&languages);
+        }
+        Commands::Author {
+            identity,
+            days,
+            top,
+        } => match analyze_author(repo, config.get_days(days), &identity)? {
+            Some(profile) => {
+                if let Some(output) = charts {
+                    output.write(&time_charts(&profile.time))?;
                 }
-                Err(e) => Err(e),
+                display_author_profile(&profile, top);
             }
+            None => println!("{} No commits found for '{}'", "⚠".yellow(), identity),
+        },
+        Commands::Experts {
+            path,
+            days,
+            half_life,
+            top,
+        } => {
+            let experts = analyze_experts(repo, config.get_days(days), &path, half_life)?;
+            let filtered = config.filter_by_teams(experts, |e| &e.contributor.email);
+            display_experts(&path, &filtered, top);
         }
-        Commands::Report { period } => {
-            let days = if period == "weekly" { 7 } else { 30 };
-            let effective_days = config.get_days(days);
-
-            println!("\n{}", format!("=== {} Report ===", period.to_uppercase()).bold().cyan());
+        Commands::Tui { days, interval } => {
+            tui::run(repo, config, config.get_days(days), interval)?;
+        }
+    }
+    Ok(())
+}
 
-            match analyze_contributors(&repo, effective_days) {
-                Ok(stats) => {
-                    let filtered_stats = config.filter_by_teams(stats, |s| &s.email);
-                    if let Err(e) = display_summary(&repo, &filtered_stats, effective_days) {
-                        eprintln!("{} {}", "Error:".red().bold(), e);
-                        std::process::exit(1);
-                    }
-                    display_contributors(&filtered_stats);
-                }
-                Err(e) => {
-                    eprintln!("{} {}", "Error:".red().bold(), e);
-                    std::process::exit(1);
-                }
-            }
+fn main() {
+    let cli = Cli::parse();
+    let config = Config::from_env();
 
-            match analyze_time_distribution(&repo, effective_days) {
-                Ok(time_stats) => display_time_analysis(&time_stats),
-                Err(e) => {
-                    eprintln!("{} {}", "Error:".red().bold(), e);
-                    std::process::exit(1);
-                }
-            }
+    // Use config to determine repo path
+    let repo_path = config.get_repo_path(cli.repo.as_deref());
 
-            match analyze_file_changes(&repo, effective_days) {
-                Ok(files) => {
-                    display_file_changes(&files, 10);
-                    Ok(())
-                }
-                Err(e) => Err(e),
-            }
+    let repo = match Repository::open(&repo_path) {
+        Ok(repo) => repo,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), e);
+            eprintln!("Make sure you're in a Git repository or specify the path with --repo");
+            std::process::exit(1);
         }
     };
 
-    if let Err(e) = result {
+    // Automatically fetch all remotes before analysis
+    fetch_all_remotes(&repo);
+
+    let defaults = SessionOptions::default();
+    stats::set_options(AnalysisOptions {
+        max_commit_lines: cli.exclude_outliers.then_some(cli.outlier_lines),
+        ignore_whitespace: cli.ignore_whitespace,
+        exclude_formatting: cli.exclude_formatting,
+        session: SessionOptions {
+            gap_minutes: cli.session_gap.or(config.session_gap).unwrap_or(defaults.gap_minutes),
+            lead_in_minutes: cli
+                .session_lead_in
+                .or(config.session_lead_in)
+                .unwrap_or(defaults.lead_in_minutes),
+        },
+    });
+
+    // Changelogs are usually pasted into release notes, so they default to Markdown
+    let format = cli.format.unwrap_or(match cli.command {
+        Commands::Changelog { .. } => OutputFormat::Markdown,
+        _ => OutputFormat::Text,
+    });
+    display::set_output_format(format);
+
+    let charts = cli.chart_out.map(|dir| ChartOutput {
+        dir,
+        png: cli.chart_png,
+    });
+
+    if let Err(e) = run(cli.command, &repo, &config, cli.outlier_lines, format, charts.as_ref()) {
         eprintln!("{} {}", "Error:".red().bold(), e);
         std::process::exit(1);
     }
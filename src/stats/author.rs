@@ -0,0 +1,191 @@
+use super::{
+    files::walk_file_changes, median, sort_files, time_to_datetime, FileSort, FileStats, GroupBy,
+    LanguageDetector, PathGrouper, TimeStats,
+};
+use chrono::{DateTime, Local, Utc};
+use git2::{Repository, Signature};
+use std::{cmp::Reverse, collections::BTreeMap, collections::HashMap};
+
+/// Everything gtct knows about one contributor
+#[derive(Debug, Default)]
+pub struct AuthorProfile {
+    pub name: String,
+    pub email: String,
+    pub commits: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    pub first_commit: Option<DateTime<Utc>>,
+    pub last_commit: Option<DateTime<Utc>>,
+    /// Commits per month, keyed by `YYYY-MM`
+    pub monthly_commits: BTreeMap<String, usize>,
+    /// Lines changed (added + deleted) by each commit
+    pub commit_sizes: Vec<usize>,
+    pub files: Vec<FileStats>,
+    pub directories: Vec<FileStats>,
+    /// Lines changed per language
+    pub languages: Vec<(String, usize)>,
+    pub time: TimeStats,
+}
+
+impl AuthorProfile {
+    /// Median lines changed per commit
+    pub fn typical_commit_size(&self) -> usize {
+        median(&self.commit_sizes)
+    }
+}
+
+/// Whether a signature matches a name or email query (case-insensitive)
+pub fn matches_identity(signature: &Signature, query: &str) -> bool {
+    signature.email().is_some_and(|email| email.eq_ignore_ascii_case(query))
+        || signature.name().is_some_and(|name| name.eq_ignore_ascii_case(query))
+}
+
+/// Build a profile of one contributor, or `None` if they have no commits in the period
+pub fn analyze_author(
+    repo: &Repository,
+    days: i64,
+    query: &str,
+) -> Result<Option<AuthorProfile>, git2::Error> {
+    let dir_grouper = PathGrouper::new(repo, GroupBy::Dir(2))?;
+    let mut detector = LanguageDetector::new(repo);
+
+    let mut profile = AuthorProfile::default();
+    let mut emails: HashMap<String, usize> = HashMap::new();
+    let mut files: HashMap<String, FileStats> = HashMap::new();
+    let mut directories: HashMap<String, FileStats> = HashMap::new();
+    let mut languages: HashMap<String, usize> = HashMap::new();
+
+    walk_file_changes(repo, days, &PathGrouper::default(), |commit, author, file_stats| {
+        if !matches_identity(author, query) {
+            return;
+        }
+
+        if profile.name.is_empty() {
+            profile.name = author.name().unwrap_or("unknown").to_string();
+        }
+        *emails.entry(author.email().unwrap_or("unknown").to_string()).or_insert(0) += 1;
+
+        let committed_at = time_to_datetime(commit.time());
+        profile.first_commit =
+            Some(profile.first_commit.map_or(committed_at, |first| first.min(committed_at)));
+        profile.last_commit = profile.last_commit.max(Some(committed_at));
+        profile.commits += 1;
+
+        let local = committed_at.with_timezone(&Local);
+        profile.time.record(local);
+        *profile.monthly_commits.entry(local.format("%Y-%m").to_string()).or_insert(0) += 1;
+
+        let mut commit_size = 0;
+        let mut touched_dirs: HashMap<String, (usize, usize)> = HashMap::new();
+        for file in file_stats {
+            commit_size += file.additions + file.deletions;
+            profile.additions += file.additions;
+            profile.deletions += file.deletions;
+            *languages.entry(detector.detect(&file.path, Some(file.blob))).or_insert(0) +=
+                file.additions + file.deletions;
+
+            let dir = touched_dirs.entry(dir_grouper.key(&file.path)).or_default();
+            dir.0 += file.additions;
+            dir.1 += file.deletions;
+
+            let entry = files.entry(file.path.clone()).or_insert_with(|| FileStats {
+                path: file.path.clone(),
+                ..Default::default()
+            });
+            entry.changes += 1;
+            entry.additions += file.additions;
+            entry.deletions += file.deletions;
+        }
+        for (dir, (additions, deletions)) in touched_dirs {
+            let entry = directories.entry(dir.clone()).or_insert_with(|| FileStats {
+                path: dir,
+                ..Default::default()
+            });
+            entry.changes += 1;
+            entry.additions += additions;
+            entry.deletions += deletions;
+        }
+        profile.commit_sizes.push(commit_size);
+    })?;
+
+    if profile.commits == 0 {
+        return Ok(None);
+    }
+
+    // The most frequently used address is the canonical one
+    profile.email = emails
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(email, _)| email)
+        .unwrap_or_default();
+    profile.files = files.into_values().collect();
+    sort_files(&mut profile.files, FileSort::Touches);
+    profile.directories = directories.into_values().collect();
+    sort_files(&mut profile.directories, FileSort::Touches);
+    profile.languages = languages.into_iter().collect();
+    profile.languages.sort_by_key(|(kind, lines)| (Reverse(*lines), kind.clone()));
+
+    Ok(Some(profile))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::tests::{commit_as, temp_repo};
+    use std::{error::Error, fs};
+
+    #[test]
+    fn test_analyze_author() -> Result<(), Box<dyn Error>> {
+        let (dir, repo) = temp_repo("author")?;
+        let mailmap: &[u8] = b"Alice <alice@example.com> <alice@old.example>\n";
+        let bob = ("Bob", "bob@example.com");
+        let docs = [(".mailmap", mailmap), ("docs/guide.md", b"# Guide\n")];
+        let first = commit_as(&repo, bob, "Add docs", 1_000, &[], &docs)?;
+
+        let mut files = docs.to_vec();
+        files.push(("src/stats/a.rs", b"fn a() {}\nfn b() {}\nfn c() {}\n"));
+        let old_alias = ("Al", "alice@old.example");
+        let second = commit_as(&repo, old_alias, "Add a.rs", 2_000, &[first], &files)?;
+
+        files[2].1 = b"fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\n";
+        files.push(("src/main.rs", b"mod stats;\nfn main() {}\n"));
+        files.push(("README.md", b"# gtct\n"));
+        let alice = ("Alice", "alice@example.com");
+        let third = commit_as(&repo, alice, "Add main", 3_000, &[second], &files)?;
+        repo.reference("refs/heads/main", third, true, "test")?;
+        repo.set_head("refs/heads/main")?;
+
+        // The old alias is merged into the .mailmap identity
+        let profile = analyze_author(&repo, 0, "ALICE@example.com")?.ok_or("no profile")?;
+        assert_eq!((profile.name.as_str(), profile.email.as_str()), ("Alice", "alice@example.com"));
+        assert_eq!((profile.commits, profile.additions, profile.deletions), (2, 7, 0));
+        assert_eq!(profile.commit_sizes, vec![4, 3]);
+
+        let files: Vec<(&str, usize, usize)> = profile
+            .files
+            .iter()
+            .map(|f| (f.path.as_str(), f.changes, f.additions))
+            .collect();
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0], ("src/stats/a.rs", 2, 4));
+        assert!(files.contains(&("src/main.rs", 1, 2)));
+        assert!(files.contains(&("README.md", 1, 1)));
+
+        let mut dirs: Vec<(&str, usize, usize)> = profile
+            .directories
+            .iter()
+            .map(|d| (d.path.as_str(), d.changes, d.additions))
+            .collect();
+        dirs.sort_unstable();
+        assert_eq!(dirs, [(".", 1, 1), ("src/", 1, 2), ("src/stats/", 2, 4)]);
+        assert_eq!(profile.languages, [("Rust".to_string(), 6), ("Markdown".to_string(), 1)]);
+
+        let bob = analyze_author(&repo, 0, "bob")?.ok_or("no profile")?;
+        assert_eq!(bob.commits, 1);
+        assert!(analyze_author(&repo, 0, "carol")?.is_none());
+
+        drop(repo);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,272 @@
+use super::{files::walk_file_changes, grouping::rollup, time_to_datetime, Interval, PathGrouper};
+use git2::{Delta, Repository};
+use std::{cmp::Reverse, collections::HashMap};
+
+/// Binary file changes for one contributor or period
+#[derive(Debug, Default, Clone)]
+pub struct BinaryStats {
+    pub label: String,
+    pub email: String,
+    pub added: usize,
+    pub modified: usize,
+    pub deleted: usize,
+    /// Size of the binary blobs written, in bytes
+    pub bytes: u64,
+}
+
+impl BinaryStats {
+    fn record(&mut self, status: Delta, size: u64) {
+        match status {
+            Delta::Added => self.added += 1,
+            Delta::Deleted => self.deleted += 1,
+            _ => self.modified += 1,
+        }
+        if status != Delta::Deleted {
+            self.bytes += size;
+        }
+    }
+
+    fn absorb(&mut self, other: &Self) {
+        self.added += other.added;
+        self.modified += other.modified;
+        self.deleted += other.deleted;
+        self.bytes += other.bytes;
+    }
+
+    /// Add up contributors' binary churn per team or period, ordered by key
+    pub fn rollup(stats: &[Self], key: impl Fn(&Self) -> String) -> Vec<Self> {
+        let new = |label| Self {
+            label,
+            ..Default::default()
+        };
+        rollup(stats, key, new, Self::absorb)
+    }
+}
+
+/// A blob written by a commit
+#[derive(Debug, Clone)]
+pub struct BlobInfo {
+    pub path: String,
+    pub size: u64,
+    pub binary: bool,
+    pub hash: String,
+    pub author: String,
+    pub email: String,
+    pub period: String,
+}
+
+/// Bytes of new blobs one commit wrote to the repository
+#[derive(Debug, Clone)]
+pub struct CommitBytes {
+    pub hash: String,
+    pub summary: String,
+    pub author: String,
+    pub email: String,
+    pub period: String,
+    /// Blobs this commit was the first to write
+    pub blobs: usize,
+    pub bytes: u64,
+    /// Of `bytes`, the share in binary files
+    pub binary_bytes: u64,
+}
+
+/// Binary churn per contributor and period, plus the largest blobs and commits
+#[derive(Debug, Default)]
+pub struct BinaryReport {
+    pub contributors: Vec<BinaryStats>,
+    /// One entry per period and contributor, labeled with the period
+    pub timeline: Vec<BinaryStats>,
+    /// Every blob written in the period, largest first
+    pub largest: Vec<BlobInfo>,
+    /// Commits that wrote new blobs, most bytes first
+    pub commits: Vec<CommitBytes>,
+}
+
+/// One binary file change, kept until it is known which commit first wrote its blob
+struct BinaryChange {
+    commit: usize,
+    path: String,
+    status: Delta,
+    blob: git2::Oid,
+    size: u64,
+}
+
+/// Blob -> (commit index, path) of its earliest writer, and what to show for it
+type BlobWriters = HashMap<git2::Oid, ((usize, String), BlobInfo)>;
+
+/// Track binary files and blob sizes, reusing the per-commit diffs of `analyze_file_changes`
+///
+/// Sizes come from the object database, so binary files that diff stats count as zero lines
+/// still show up. Bytes are only counted for the earliest commit in the period that wrote a
+/// blob, so the same content written on another branch or under another path adds nothing.
+pub fn analyze_binaries(
+    repo: &Repository,
+    days: i64,
+    interval: Interval,
+) -> Result<BinaryReport, git2::Error> {
+    let odb = repo.odb()?;
+    let mut commits: Vec<(i64, CommitBytes)> = Vec::new();
+    let mut changes: Vec<BinaryChange> = Vec::new();
+    let mut blobs: BlobWriters = HashMap::new();
+
+    walk_file_changes(repo, days, &PathGrouper::default(), |commit, author, files| {
+        // Merges only repeat blobs written on the merged branch
+        if commit.parent_count() > 1 {
+            return;
+        }
+        let index = commits.len();
+        let time = commit.time().seconds();
+        let info = CommitBytes {
+            hash: commit.id().to_string()[..7].to_string(),
+            summary: commit.summary().unwrap_or_default().to_string(),
+            author: author.name().unwrap_or("unknown").to_string(),
+            email: author.email().unwrap_or("unknown").to_string(),
+            period: interval.bucket(time_to_datetime(commit.time())),
+            blobs: 0,
+            bytes: 0,
+            binary_bytes: 0,
+        };
+
+        for file in files {
+            let size = if file.status == Delta::Deleted {
+                0
+            } else {
+                odb.read_header(file.blob).map_or(0, |(size, _)| size as u64)
+            };
+
+            if file.status != Delta::Deleted {
+                // Commits are visited newest first, so a later visit is an earlier writer
+                let earlier = blobs
+                    .get(&file.blob)
+                    .map_or(true, |((writer, _), _)| commits[*writer].0 >= time);
+                if earlier {
+                    let blob = BlobInfo {
+                        path: file.path.clone(),
+                        size,
+                        binary: file.binary,
+                        hash: info.hash.clone(),
+                        author: info.author.clone(),
+                        email: info.email.clone(),
+                        period: info.period.clone(),
+                    };
+                    blobs.insert(file.blob, ((index, file.path.clone()), blob));
+                }
+            }
+
+            if file.binary {
+                changes.push(BinaryChange {
+                    commit: index,
+                    path: file.path.clone(),
+                    status: file.status,
+                    blob: file.blob,
+                    size,
+                });
+            }
+        }
+        commits.push((time, info));
+    })?;
+
+    let (contributors, timeline) = tally_changes(&changes, &commits, &blobs);
+
+    let mut largest = Vec::with_capacity(blobs.len());
+    for ((writer, _), blob) in blobs.into_values() {
+        let (_, commit) = &mut commits[writer];
+        commit.blobs += 1;
+        commit.bytes += blob.size;
+        if blob.binary {
+            commit.binary_bytes += blob.size;
+        }
+        largest.push(blob);
+    }
+    largest.sort_by_key(|b| Reverse(b.size));
+    let mut commits: Vec<CommitBytes> =
+        commits.into_iter().map(|(_, commit)| commit).filter(|c| c.blobs > 0).collect();
+    commits.sort_by_key(|c| Reverse(c.bytes));
+
+    Ok(BinaryReport {
+        contributors,
+        timeline,
+        largest,
+        commits,
+    })
+}
+
+/// Binary changes per contributor (most bytes first) and per period and contributor,
+/// counting bytes only where a change was its blob's first write
+fn tally_changes(
+    changes: &[BinaryChange],
+    commits: &[(i64, CommitBytes)],
+    blobs: &BlobWriters,
+) -> (Vec<BinaryStats>, Vec<BinaryStats>) {
+    let mut contributor_map: HashMap<String, BinaryStats> = HashMap::new();
+    let mut timeline_map: HashMap<(String, String), BinaryStats> = HashMap::new();
+    for change in changes {
+        let first_write = blobs
+            .get(&change.blob)
+            .is_some_and(|((writer, path), _)| *writer == change.commit && *path == change.path);
+        let size = if first_write { change.size } else { 0 };
+        let (_, commit) = &commits[change.commit];
+        contributor_map
+            .entry(format!("{}|{}", commit.author, commit.email))
+            .or_insert_with(|| BinaryStats {
+                label: commit.author.clone(),
+                email: commit.email.clone(),
+                ..Default::default()
+            })
+            .record(change.status, size);
+        timeline_map
+            .entry((commit.period.clone(), commit.email.clone()))
+            .or_insert_with(|| BinaryStats {
+                label: commit.period.clone(),
+                email: commit.email.clone(),
+                ..Default::default()
+            })
+            .record(change.status, size);
+    }
+
+    let mut contributors: Vec<BinaryStats> = contributor_map.into_values().collect();
+    contributors.sort_by_key(|s| Reverse(s.bytes));
+    (contributors, timeline_map.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::tests::{commit, temp_repo};
+    use std::{error::Error, fs};
+
+    #[test]
+    fn test_blob_counted_once_across_paths() -> Result<(), Box<dyn Error>> {
+        let (dir, repo) = temp_repo("binaries")?;
+        let image = [&[0_u8, 1, 2][..], &[7; 997]].concat();
+        let icon = [0_u8; 10];
+
+        let first = commit(&repo, 100, &[], &[("a.png", &image)])?;
+        let copy = commit(&repo, 200, &[first], &[("a.png", &image), ("b.png", &image)])?;
+        let files = [
+            ("a.png", &image[..]),
+            ("b.png", &image),
+            ("c.png", &image),
+            ("d.png", &icon),
+        ];
+        let last = commit(&repo, 300, &[copy], &files)?;
+        repo.reference("refs/heads/main", last, true, "test")?;
+
+        let report = analyze_binaries(&repo, 0, Interval::Month)?;
+        let bytes: Vec<(String, u64)> =
+            report.commits.iter().map(|c| (c.hash.clone(), c.bytes)).collect();
+        assert_eq!(
+            bytes,
+            [
+                (first.to_string()[..7].to_string(), 1000),
+                (last.to_string()[..7].to_string(), 10)
+            ]
+        );
+        assert_eq!(report.contributors[0].added, 4);
+        assert_eq!(report.contributors[0].bytes, 1010);
+
+        drop(repo);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use super::{canonical_author, time_to_datetime};
+use chrono::{DateTime, Utc};
+use git2::{BranchType, Oid, Repository};
+use std::cmp::Reverse;
+
+/// Names tried, in order, when no main branch is configured
+const MAIN_BRANCH_CANDIDATES: [&str; 4] = ["main", "master", "origin/main", "origin/master"];
+
+/// A local or remote branch compared with the main branch
+#[derive(Debug, Clone)]
+pub struct BranchInfo {
+    pub name: String,
+    pub remote: bool,
+    pub hash: String,
+    pub last_commit: DateTime<Utc>,
+    /// Author of the last commit
+    pub author: String,
+    pub email: String,
+    /// Commits on the branch that are not on the main branch
+    pub ahead: usize,
+    /// Commits on the main branch that are not on the branch
+    pub behind: usize,
+    /// Every commit on the branch is reachable from the main branch
+    pub merged: bool,
+    /// Days since the branch forked off the main branch
+    pub age_days: i64,
+    /// Days since the last commit
+    pub idle_days: i64,
+}
+
+impl BranchInfo {
+    pub const fn is_stale(&self, stale_days: i64) -> bool {
+        self.idle_days > stale_days
+    }
+}
+
+/// Branches and the main branch they were compared with
+#[derive(Debug, Default)]
+pub struct BranchReport {
+    pub main_branch: String,
+    /// Least recently updated first
+    pub branches: Vec<BranchInfo>,
+}
+
+/// Find the main branch: `name` if given, else the first of main/master that exists
+pub fn resolve_main_branch(
+    repo: &Repository,
+    name: Option<&str>,
+) -> Result<(String, Oid), git2::Error> {
+    let lookup = |name: &str| {
+        repo.find_branch(name, BranchType::Local)
+            .or_else(|_| repo.find_branch(name, BranchType::Remote))
+            .and_then(|branch| branch.get().peel_to_commit())
+            .map(|commit| (name.to_string(), commit.id()))
+    };
+
+    if let Some(name) = name {
+        return lookup(name);
+    }
+    MAIN_BRANCH_CANDIDATES.iter().find_map(|name| lookup(name).ok()).ok_or_else(|| {
+        git2::Error::from_str("no main branch found; set MAIN_BRANCH or pass --main")
+    })
+}
+
+/// List local and remote branches with ahead/behind counts versus the main branch
+pub fn analyze_branches(
+    repo: &Repository,
+    main_branch: Option<&str>,
+) -> Result<BranchReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let (main_name, main_oid) = resolve_main_branch(repo, main_branch)?;
+    let now = Utc::now();
+    let mut branches = Vec::new();
+
+    for branch in repo.branches(None)? {
+        let (branch, branch_type) = branch?;
+        // Skip symbolic refs such as origin/HEAD
+        if branch.get().symbolic_target().is_some() {
+            continue;
+        }
+        let Some(name) = branch.name()?.map(str::to_string) else {
+            continue;
+        };
+        if name == main_name {
+            continue;
+        }
+
+        let commit = branch.get().peel_to_commit()?;
+        let (ahead, behind) = repo.graph_ahead_behind(commit.id(), main_oid)?;
+        let forked_at = match repo.merge_base(commit.id(), main_oid) {
+            Ok(base) => repo.find_commit(base)?.time(),
+            // Unrelated history: count from the branch's last commit
+            Err(_) => commit.time(),
+        };
+        let last_commit = time_to_datetime(commit.time());
+        let author = canonical_author(&commit, mailmap.as_ref());
+
+        branches.push(BranchInfo {
+            name,
+            remote: branch_type == BranchType::Remote,
+            hash: commit.id().to_string()[..7].to_string(),
+            last_commit,
+            author: author.name().unwrap_or("unknown").to_string(),
+            email: author.email().unwrap_or("unknown").to_string(),
+            ahead,
+            behind,
+            merged: ahead == 0,
+            age_days: (now - time_to_datetime(forked_at)).num_days(),
+            idle_days: (now - last_commit).num_days(),
+        });
+    }
+
+    branches.sort_by_key(|b| Reverse(b.idle_days));
+    Ok(BranchReport {
+        main_branch: main_name,
+        branches,
+    })
+}
@@ -0,0 +1,252 @@
+use super::{
+    canonical_author, classify_message, time_to_datetime, CommitClass, CommitType, IssueMatcher,
+    IssueRef,
+};
+use chrono::{DateTime, Utc};
+use git2::{Oid, Repository, Sort};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// One commit in a changelog
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangelogEntry {
+    pub hash: String,
+    #[serde(rename = "type")]
+    pub kind: CommitType,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub description: String,
+    pub author: String,
+    pub email: String,
+    pub date: DateTime<Utc>,
+    pub issues: Vec<IssueRef>,
+}
+
+/// Entries of one scope within a commit type (`None` for unscoped commits)
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangelogScope {
+    pub scope: Option<String>,
+    pub entries: Vec<ChangelogEntry>,
+}
+
+/// Entries of one commit type, unscoped first and then by scope
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangelogSection {
+    #[serde(rename = "type")]
+    pub kind: CommitType,
+    pub title: String,
+    pub scopes: Vec<ChangelogScope>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangelogContributor {
+    pub name: String,
+    pub email: String,
+    pub commits: usize,
+    /// No commits reachable from the start of the range
+    pub first_time: bool,
+}
+
+/// Commits between two revisions, grouped by type and scope
+#[derive(Debug, Clone, Serialize)]
+pub struct Changelog {
+    pub from: Option<String>,
+    pub to: String,
+    pub date: DateTime<Utc>,
+    pub sections: Vec<ChangelogSection>,
+    pub contributors: Vec<ChangelogContributor>,
+}
+
+impl Changelog {
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &ChangelogEntry> {
+        self.sections
+            .iter()
+            .flat_map(|s| &s.scopes)
+            .flat_map(|s| &s.entries)
+            .filter(|e| e.breaking)
+    }
+}
+
+/// Split entries into runs of the same scope, unscoped first, keeping their order within a scope
+fn group_by_scope(mut entries: Vec<ChangelogEntry>) -> Vec<ChangelogScope> {
+    entries.sort_by(|a, b| a.scope.cmp(&b.scope));
+    let mut scopes: Vec<ChangelogScope> = Vec::new();
+    for entry in entries {
+        match scopes.last_mut() {
+            Some(last) if last.scope == entry.scope => last.entries.push(entry),
+            _ => scopes.push(ChangelogScope {
+                scope: entry.scope.clone(),
+                entries: vec![entry],
+            }),
+        }
+    }
+    scopes
+}
+
+/// Split `from..to` into its ends; a missing end means HEAD (or the root, for `from`)
+pub fn parse_range(range: &str) -> (Option<&str>, &str) {
+    match range.split_once("..") {
+        Some((from, to)) => {
+            let from = (!from.is_empty()).then_some(from);
+            let to = if to.is_empty() { "HEAD" } else { to };
+            (from, to)
+        }
+        None => (None, range),
+    }
+}
+
+/// Emails of everyone with a commit reachable from `oid`
+pub fn authors_reachable_from(repo: &Repository, oid: Oid) -> Result<HashSet<String>, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(oid)?;
+
+    let mut emails = HashSet::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        let author = canonical_author(&commit, mailmap.as_ref());
+        emails.insert(author.email().unwrap_or("unknown").to_lowercase());
+    }
+    Ok(emails)
+}
+
+/// Build a changelog for `from..to` (merge commits are left out)
+pub fn analyze_changelog(
+    repo: &Repository,
+    from: Option<&str>,
+    to: &str,
+    issues: &IssueMatcher,
+) -> Result<Changelog, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let to_commit = repo.revparse_single(to)?.peel_to_commit()?;
+    let from_oid = from.map(|from| repo.revparse_single(from)?.peel_to_commit()).transpose()?;
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(Sort::TIME)?;
+    revwalk.push(to_commit.id())?;
+    let known_authors = match &from_oid {
+        Some(from_commit) => {
+            revwalk.hide(from_commit.id())?;
+            authors_reachable_from(repo, from_commit.id())?
+        }
+        None => HashSet::new(),
+    };
+
+    let mut by_type: HashMap<CommitType, Vec<ChangelogEntry>> = HashMap::new();
+    let mut contributor_map: HashMap<String, ChangelogContributor> = HashMap::new();
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+        if commit.parent_count() > 1 {
+            continue;
+        }
+
+        let message = commit.message().unwrap_or_default();
+        let CommitClass {
+            kind,
+            scope,
+            breaking,
+            description,
+            ..
+        } = classify_message(message);
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let name = author.name().unwrap_or("unknown").to_string();
+        let email = author.email().unwrap_or("unknown").to_string();
+
+        contributor_map
+            .entry(email.to_lowercase())
+            .or_insert_with(|| ChangelogContributor {
+                name: name.clone(),
+                email: email.clone(),
+                commits: 0,
+                first_time: from_oid.is_some() && !known_authors.contains(&email.to_lowercase()),
+            })
+            .commits += 1;
+
+        by_type.entry(kind).or_default().push(ChangelogEntry {
+            hash: oid.to_string()[..7].to_string(),
+            kind,
+            scope,
+            breaking,
+            description,
+            author: name,
+            email,
+            date: time_to_datetime(commit.time()),
+            issues: issues.find(message),
+        });
+    }
+
+    let sections = CommitType::ALL
+        .into_iter()
+        .filter_map(|kind| {
+            let mut entries = by_type.remove(&kind)?;
+            // Oldest first within each scope
+            entries.reverse();
+            Some(ChangelogSection {
+                kind,
+                title: kind.title().to_string(),
+                scopes: group_by_scope(entries),
+            })
+        })
+        .collect();
+
+    let mut contributors: Vec<ChangelogContributor> = contributor_map.into_values().collect();
+    contributors.sort_by(|a, b| b.commits.cmp(&a.commits).then_with(|| a.name.cmp(&b.name)));
+
+    Ok(Changelog {
+        from: from.map(str::to_string),
+        to: to.to_string(),
+        date: time_to_datetime(to_commit.time()),
+        sections,
+        contributors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("v1.0..v1.1"), (Some("v1.0"), "v1.1"));
+        assert_eq!(parse_range("v1.0.."), (Some("v1.0"), "HEAD"));
+        assert_eq!(parse_range("v1.1"), (None, "v1.1"));
+    }
+
+    #[test]
+    fn test_group_by_scope() {
+        let entry = |scope: Option<&str>, description: &str| ChangelogEntry {
+            hash: String::new(),
+            kind: CommitType::Feature,
+            scope: scope.map(str::to_string),
+            breaking: false,
+            description: description.to_string(),
+            author: String::new(),
+            email: String::new(),
+            date: Utc::now(),
+            issues: Vec::new(),
+        };
+        let scopes = group_by_scope(vec![
+            entry(Some("parser"), "first"),
+            entry(None, "second"),
+            entry(Some("cli"), "third"),
+            entry(Some("parser"), "fourth"),
+        ]);
+        let grouped: Vec<(Option<&str>, Vec<&str>)> = scopes
+            .iter()
+            .map(|s| {
+                let entries = s.entries.iter().map(|e| e.description.as_str()).collect();
+                (s.scope.as_deref(), entries)
+            })
+            .collect();
+        assert_eq!(
+            grouped,
+            [
+                (None, vec!["second"]),
+                (Some("cli"), vec!["third"]),
+                (Some("parser"), vec!["first", "fourth"]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use super::{canonical_author, grouping::rollup, is_included, time_to_datetime, Interval};
+use git2::Repository;
+use serde::{Serialize, Serializer};
+use std::{cmp::Reverse, collections::HashMap, fmt};
+
+/// Kind of work a commit represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CommitType {
+    Feature,
+    Fix,
+    Refactor,
+    Perf,
+    Docs,
+    Test,
+    Build,
+    Ci,
+    Chore,
+    Style,
+    Revert,
+    Other,
+}
+
+impl CommitType {
+    /// All types, in display order
+    pub const ALL: [Self; 12] = [
+        Self::Feature,
+        Self::Fix,
+        Self::Refactor,
+        Self::Perf,
+        Self::Docs,
+        Self::Test,
+        Self::Build,
+        Self::Ci,
+        Self::Chore,
+        Self::Style,
+        Self::Revert,
+        Self::Other,
+    ];
+
+    /// Conventional Commits type name
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Feature => "feat",
+            Self::Fix => "fix",
+            Self::Refactor => "refactor",
+            Self::Perf => "perf",
+            Self::Docs => "docs",
+            Self::Test => "test",
+            Self::Build => "build",
+            Self::Ci => "ci",
+            Self::Chore => "chore",
+            Self::Style => "style",
+            Self::Revert => "revert",
+            Self::Other => "other",
+        }
+    }
+
+    /// Section heading used in changelogs
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Feature => "Features",
+            Self::Fix => "Bug Fixes",
+            Self::Refactor => "Refactoring",
+            Self::Perf => "Performance",
+            Self::Docs => "Documentation",
+            Self::Test => "Tests",
+            Self::Build => "Build System",
+            Self::Ci => "Continuous Integration",
+            Self::Chore => "Chores",
+            Self::Style => "Style",
+            Self::Revert => "Reverts",
+            Self::Other => "Other Changes",
+        }
+    }
+
+    /// Map a Conventional Commits type (or a common alias) to a commit type
+    pub fn from_conventional(kind: &str) -> Self {
+        match kind.to_ascii_lowercase().as_str() {
+            "feat" | "feature" => Self::Feature,
+            "fix" | "bugfix" | "hotfix" => Self::Fix,
+            "refactor" => Self::Refactor,
+            "perf" => Self::Perf,
+            "docs" | "doc" => Self::Docs,
+            "test" | "tests" => Self::Test,
+            "build" | "deps" => Self::Build,
+            "ci" => Self::Ci,
+            "chore" | "release" => Self::Chore,
+            "style" => Self::Style,
+            "revert" => Self::Revert,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl Serialize for CommitType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl fmt::Display for CommitType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A commit subject broken into its Conventional Commits parts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitClass {
+    pub kind: CommitType,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub description: String,
+    /// Whether the subject followed the Conventional Commits format
+    pub conventional: bool,
+}
+
+/// Parse a `type(scope)!: description` subject
+pub fn parse_conventional(subject: &str) -> Option<CommitClass> {
+    let (header, description) = subject.split_once(':')?;
+    let (header, breaking) =
+        header.strip_suffix('!').map_or((header, false), |header| (header, true));
+    let (kind, scope) = match header.split_once('(') {
+        Some((kind, scope)) => (kind, Some(scope.strip_suffix(')')?.trim().to_string())),
+        None => (header, None),
+    };
+
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    // Unknown capitalized words ("Note: ...") are prose, not a type
+    let commit_type = CommitType::from_conventional(kind);
+    if commit_type == CommitType::Other && kind.chars().any(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+
+    Some(CommitClass {
+        kind: commit_type,
+        scope: scope.filter(|scope| !scope.is_empty()),
+        breaking,
+        description: description.trim().to_string(),
+        conventional: true,
+    })
+}
+
+/// Keywords for subjects that don't follow the convention, checked in order
+///
+/// Only whole words match, so each keyword lists the inflections it covers: "Update
+/// Dockerfile" is not docs and "Address review" is not a feature.
+const KEYWORDS: &[(CommitType, &[&str])] = &[
+    (CommitType::Revert, &["revert", "reverts", "reverted", "reverting"]),
+    (
+        CommitType::Fix,
+        &[
+            "fix", "fixes", "fixed", "fixing", "bug", "bugs", "bugfix", "hotfix", "patch",
+            "resolve", "resolves", "resolved", "crash", "crashes", "repair", "repaired",
+        ],
+    ),
+    (
+        CommitType::Refactor,
+        &[
+            "refactor",
+            "refactored",
+            "refactoring",
+            "cleanup",
+            "restructure",
+            "rename",
+            "renamed",
+            "simplify",
+            "simplified",
+        ],
+    ),
+    (CommitType::Docs, &["doc", "docs", "documentation", "readme", "changelog"]),
+    (CommitType::Test, &["test", "tests", "testing", "spec", "specs"]),
+    (
+        CommitType::Perf,
+        &[
+            "perf",
+            "performance",
+            "optimize",
+            "optimise",
+            "optimized",
+            "optimised",
+            "optimization",
+            "optimisation",
+            "speedup",
+        ],
+    ),
+    (
+        CommitType::Chore,
+        &[
+            "bump", "bumped", "upgrade", "upgraded", "release", "merge", "wip",
+        ],
+    ),
+    (CommitType::Ci, &["ci", "pipeline", "workflow", "workflows"]),
+    (
+        CommitType::Feature,
+        &[
+            "add",
+            "adds",
+            "added",
+            "adding",
+            "implement",
+            "implemented",
+            "introduce",
+            "introduced",
+            "support",
+            "feature",
+            "new",
+        ],
+    ),
+];
+
+/// Classify a commit message: Conventional Commits first, then keyword heuristics
+pub fn classify_message(message: &str) -> CommitClass {
+    let subject = message.lines().next().unwrap_or_default().trim();
+    if let Some(class) = parse_conventional(subject) {
+        return class;
+    }
+
+    let lowercase = subject.to_lowercase();
+    let words: Vec<&str> = lowercase
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let kind = KEYWORDS
+        .iter()
+        .find(|(_, keywords)| words.iter().any(|word| keywords.contains(word)))
+        .map_or(CommitType::Other, |(kind, _)| *kind);
+
+    CommitClass {
+        kind,
+        scope: None,
+        breaking: message.contains("BREAKING CHANGE"),
+        description: subject.to_string(),
+        conventional: false,
+    }
+}
+
+/// Commit counts per type for one contributor, team or period
+#[derive(Debug, Default, Clone)]
+pub struct TypeBreakdown {
+    pub label: String,
+    pub email: String,
+    pub counts: HashMap<CommitType, usize>,
+    /// Commits following the Conventional Commits format
+    pub conventional: usize,
+}
+
+impl TypeBreakdown {
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    pub fn count(&self, kind: CommitType) -> usize {
+        self.counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    pub fn record(&mut self, class: &CommitClass) {
+        *self.counts.entry(class.kind).or_insert(0) += 1;
+        if class.conventional {
+            self.conventional += 1;
+        }
+    }
+
+    /// Merge another breakdown's counts into this one
+    pub fn absorb(&mut self, other: &Self) {
+        for (kind, count) in &other.counts {
+            *self.counts.entry(*kind).or_insert(0) += count;
+        }
+        self.conventional += other.conventional;
+    }
+
+    /// Sum commit type counts per team or period, ordered by key
+    pub fn rollup(breakdowns: &[Self], key: impl Fn(&Self) -> String) -> Vec<Self> {
+        let new = |label| Self {
+            label,
+            ..Default::default()
+        };
+        rollup(breakdowns, key, new, Self::absorb)
+    }
+}
+
+/// Commit type breakdowns per contributor and per period
+#[derive(Debug, Default)]
+pub struct CommitTypeReport {
+    pub contributors: Vec<TypeBreakdown>,
+    /// One entry per period and contributor, labeled with the period
+    pub timeline: Vec<TypeBreakdown>,
+}
+
+pub fn analyze_commit_types(
+    repo: &Repository,
+    days: i64,
+    interval: Interval,
+) -> Result<CommitTypeReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut contributor_map: HashMap<String, TypeBreakdown> = HashMap::new();
+    let mut timeline_map: HashMap<(String, String), TypeBreakdown> = HashMap::new();
+
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        if !is_included(&commit, repo, days) {
+            continue;
+        }
+
+        let class = classify_message(commit.message().unwrap_or_default());
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let email = author.email().unwrap_or("unknown").to_string();
+        let name = author.name().unwrap_or("unknown").to_string();
+
+        contributor_map
+            .entry(format!("{name}|{email}"))
+            .or_insert_with(|| TypeBreakdown {
+                label: name.clone(),
+                email: email.clone(),
+                ..Default::default()
+            })
+            .record(&class);
+
+        let bucket = interval.bucket(time_to_datetime(commit.time()));
+        timeline_map
+            .entry((bucket.clone(), email.clone()))
+            .or_insert_with(|| TypeBreakdown {
+                label: bucket,
+                email,
+                ..Default::default()
+            })
+            .record(&class);
+    }
+
+    let mut contributors: Vec<TypeBreakdown> = contributor_map.into_values().collect();
+    contributors.sort_by_key(|b| Reverse(b.total()));
+    Ok(CommitTypeReport {
+        contributors,
+        timeline: timeline_map.into_values().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_conventional() {
+        assert_eq!(
+            parse_conventional("feat(api): add endpoint"),
+            Some(CommitClass {
+                kind: CommitType::Feature,
+                scope: Some("api".to_string()),
+                breaking: false,
+                description: "add endpoint".to_string(),
+                conventional: true,
+            })
+        );
+        assert_eq!(
+            parse_conventional("fix!: drop old flag"),
+            Some(CommitClass {
+                kind: CommitType::Fix,
+                scope: None,
+                breaking: true,
+                description: "drop old flag".to_string(),
+                conventional: true,
+            })
+        );
+
+        assert!(parse_conventional("Update README.md").is_none());
+        assert!(parse_conventional("Note: this is not a type").is_none());
+        assert!(parse_conventional("feat(api: broken").is_none());
+    }
+
+    #[test]
+    fn test_classify_heuristic() {
+        assert_eq!(classify_message("Fixed crash on startup").kind, CommitType::Fix);
+        assert_eq!(classify_message("Add dark mode").kind, CommitType::Feature);
+        assert_eq!(classify_message("Revert \"Add dark mode\"").kind, CommitType::Revert);
+        assert_eq!(classify_message("Update README").kind, CommitType::Docs);
+        assert_eq!(classify_message("Use a prefix tree").kind, CommitType::Other);
+        assert!(!classify_message("Add dark mode").conventional);
+    }
+
+    #[test]
+    fn test_classify_heuristic_whole_words() {
+        // Keywords that only begin these words don't count
+        for subject in [
+            "Update Dockerfile",
+            "Address review comments",
+            "Additional logging for city lookups",
+            "Specify the timeout",
+            "Newline at end of file",
+            "Bugzilla sync",
+        ] {
+            assert_eq!(classify_message(subject).kind, CommitType::Other, "{subject}");
+        }
+        assert_eq!(classify_message("Set up CI workflow").kind, CommitType::Ci);
+        assert_eq!(classify_message("Added specs for parser").kind, CommitType::Test);
+    }
+}
@@ -1,22 +1,197 @@
-use super::{get_commit_stats, is_within_days};
-use git2::Repository;
-use std::collections::HashMap;
+use super::{
+    canonical_author,
+    effort::estimate_effort,
+    get_commit_stats, get_file_line_stats, is_included,
+    pull_requests::{pr_author, pr_number},
+    time_to_datetime, PathGrouper,
+};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use git2::{Commit, Repository, Signature};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Default, Clone)]
 pub struct ContributorStats {
     pub name: String,
     pub email: String,
     pub commits: usize,
+    /// Squash or merge commits that deliver a pull request
+    pub prs: usize,
     pub additions: usize,
     pub deletions: usize,
     pub files_changed: usize,
+    /// Earliest and latest commit in the analyzed period
+    pub first_commit: Option<DateTime<Utc>>,
+    pub last_commit: Option<DateTime<Utc>>,
+    /// Distinct days and ISO weeks with at least one commit
+    pub active_days: usize,
+    pub active_weeks: usize,
+    /// Work sessions and estimated minutes of effort, from author timestamps
+    pub sessions: usize,
+    pub effort_minutes: usize,
+}
+
+impl ContributorStats {
+    /// Widen the first/last commit span to include `at`
+    pub fn record_commit_time(&mut self, at: DateTime<Utc>) {
+        self.first_commit = Some(self.first_commit.map_or(at, |first| first.min(at)));
+        self.last_commit = self.last_commit.max(Some(at));
+    }
 }
 
 pub fn analyze_contributors(
     repo: &Repository,
     days: i64,
 ) -> Result<Vec<ContributorStats>, git2::Error> {
-    let mut stats_map: HashMap<String, ContributorStats> = HashMap::new();
+    walk_contributors(repo, days, |_, _, _| {})
+}
+
+/// Per-contributor totals built up one commit at a time, keyed by `name|email`
+///
+/// Shared by the contributor analysis and the dashboard, so both count commits, active days
+/// and effort the same way.
+#[derive(Debug, Default)]
+pub struct ContributorTally {
+    stats: HashMap<String, ContributorStats>,
+    active_dates: HashMap<String, HashSet<NaiveDate>>,
+    author_times: HashMap<String, Vec<i64>>,
+}
+
+impl ContributorTally {
+    /// Count one commit by `author` (already resolved through .mailmap) with its
+    /// `(additions, deletions, files)`, returning the contributor's key
+    pub fn record(
+        &mut self,
+        commit: &Commit,
+        author: &Signature,
+        (additions, deletions, files): (usize, usize, usize),
+    ) -> String {
+        let name = author.name().unwrap_or("unknown");
+        let email = author.email().unwrap_or("unknown");
+        let key = format!("{name}|{email}");
+        let entry = self.stats.entry(key.clone()).or_insert_with(|| ContributorStats {
+            name: name.to_string(),
+            email: email.to_string(),
+            ..Default::default()
+        });
+
+        entry.commits += 1;
+        entry.additions += additions;
+        entry.deletions += deletions;
+        entry.files_changed += files;
+        let committed_at = time_to_datetime(commit.time());
+        entry.record_commit_time(committed_at);
+        self.active_dates
+            .entry(key.clone())
+            .or_default()
+            .insert(committed_at.date_naive());
+        self.author_times.entry(key.clone()).or_default().push(author.when().seconds());
+        key
+    }
+
+    /// Credit pull requests to a contributor who has commits in the tally
+    fn add_prs(&mut self, key: &str, prs: usize) {
+        if let Some(entry) = self.stats.get_mut(key) {
+            entry.prs += prs;
+        }
+    }
+
+    /// Fill in active days and weeks and effort estimates, most commits first
+    pub fn finish(mut self) -> Vec<ContributorStats> {
+        for (key, dates) in self.active_dates {
+            if let Some(entry) = self.stats.get_mut(&key) {
+                let weeks: HashSet<(i32, u32)> = dates
+                    .iter()
+                    .map(|date| (date.iso_week().year(), date.iso_week().week()))
+                    .collect();
+                entry.active_days = dates.len();
+                entry.active_weeks = weeks.len();
+            }
+        }
+        let session = super::options().session;
+        for (key, times) in self.author_times {
+            if let Some(entry) = self.stats.get_mut(&key) {
+                (entry.sessions, entry.effort_minutes) = estimate_effort(&times, session);
+            }
+        }
+
+        let mut stats: Vec<ContributorStats> = self.stats.into_values().collect();
+        stats.sort_by_key(|s| std::cmp::Reverse(s.commits));
+        stats
+    }
+}
+
+/// Walk commits and aggregate per-contributor stats, handing each commit, its contributor
+/// key (`name|email`, after .mailmap) and its `(additions, deletions)` to `visit` for
+/// further analysis
+pub fn walk_contributors(
+    repo: &Repository,
+    days: i64,
+    mut visit: impl FnMut(&Commit, &str, (usize, usize)),
+) -> Result<Vec<ContributorStats>, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut tally = ContributorTally::default();
+    // PRs are credited to the branch author, who may not have committed the merge
+    let mut pr_credits: HashMap<String, usize> = HashMap::new();
+    let mut seen_prs: HashSet<u32> = HashSet::new();
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        if !is_included(&commit, repo, days) {
+            continue;
+        }
+
+        // A backported or cherry-picked PR is credited once
+        if pr_number(&commit).is_some_and(|number| seen_prs.insert(number)) {
+            let (pr_name, pr_email) = pr_author(&commit, mailmap.as_ref());
+            *pr_credits.entry(format!("{pr_name}|{pr_email}")).or_insert(0) += 1;
+        }
+
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let (additions, deletions, files) = get_commit_stats(&commit, repo).unwrap_or_default();
+        let key = tally.record(&commit, &author, (additions, deletions, files));
+        visit(&commit, &key, (additions, deletions));
+    }
+
+    for (key, prs) in pr_credits {
+        tally.add_prs(&key, prs);
+    }
+    Ok(tally.finish())
+}
+
+/// Contributor statistics rolled up into one directory or module
+#[derive(Debug, Default, Clone)]
+pub struct ContributorGroup {
+    pub path: String,
+    pub contributors: Vec<ContributorStats>,
+}
+
+impl ContributorGroup {
+    pub fn commits(&self) -> usize {
+        self.contributors.iter().map(|c| c.commits).sum()
+    }
+
+    pub fn additions(&self) -> usize {
+        self.contributors.iter().map(|c| c.additions).sum()
+    }
+
+    pub fn deletions(&self) -> usize {
+        self.contributors.iter().map(|c| c.deletions).sum()
+    }
+}
+
+/// Analyze contributors per group, counting only the lines each commit changed in that group
+pub fn analyze_contributor_groups(
+    repo: &Repository,
+    days: i64,
+    grouper: &PathGrouper,
+) -> Result<Vec<ContributorGroup>, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut group_map: HashMap<String, HashMap<String, ContributorStats>> = HashMap::new();
     let mut revwalk = repo.revwalk()?;
     // Analyze all branches, not just HEAD
     revwalk.push_glob("refs/*")?;
@@ -25,32 +200,53 @@ pub fn analyze_contributors(
         let oid = oid?;
         let commit = repo.find_commit(oid)?;
 
-        if !is_within_days(commit.time(), days) {
+        if !is_included(&commit, repo, days) {
             continue;
         }
 
-        let author = commit.author();
+        let author = canonical_author(&commit, mailmap.as_ref());
         let email = author.email().unwrap_or("unknown").to_string();
         let name = author.name().unwrap_or("unknown").to_string();
+        let key = format!("{name}|{email}");
 
-        let key = format!("{}|{}", name, email);
-        let entry = stats_map.entry(key).or_insert_with(|| ContributorStats {
-            name: name.clone(),
-            email: email.clone(),
-            ..Default::default()
-        });
+        let Ok(files) = get_file_line_stats(&commit, repo) else {
+            continue;
+        };
 
-        entry.commits += 1;
+        // (additions, deletions, files) per group touched by this commit
+        let mut touched: HashMap<String, (usize, usize, usize)> = HashMap::new();
+        for file in files {
+            let totals = touched.entry(grouper.key(&file.path)).or_default();
+            totals.0 += file.additions;
+            totals.1 += file.deletions;
+            totals.2 += 1;
+        }
 
-        // Analyze diff for additions/deletions
-        if let Ok((additions, deletions, files)) = get_commit_stats(&commit, repo) {
+        for (group, (additions, deletions, files)) in touched {
+            let entry =
+                group_map.entry(group).or_default().entry(key.clone()).or_insert_with(|| {
+                    ContributorStats {
+                        name: name.clone(),
+                        email: email.clone(),
+                        ..Default::default()
+                    }
+                });
+            entry.commits += 1;
             entry.additions += additions;
             entry.deletions += deletions;
             entry.files_changed += files;
+            entry.record_commit_time(time_to_datetime(commit.time()));
         }
     }
 
-    let mut stats: Vec<ContributorStats> = stats_map.into_values().collect();
-    stats.sort_by(|a, b| b.commits.cmp(&a.commits));
-    Ok(stats)
+    let mut groups: Vec<ContributorGroup> = group_map
+        .into_iter()
+        .map(|(path, contributors)| {
+            let mut contributors: Vec<ContributorStats> = contributors.into_values().collect();
+            contributors.sort_by_key(|s| std::cmp::Reverse(s.commits));
+            ContributorGroup { path, contributors }
+        })
+        .collect();
+    groups.sort_by_key(|g| std::cmp::Reverse(g.commits()));
+    Ok(groups)
 }
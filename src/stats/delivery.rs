@@ -0,0 +1,233 @@
+use super::{
+    canonical_author, get_commit_stats, grouping::rollup, is_within_days, median,
+    merges::parse_merge_message, percentile, time_to_datetime,
+};
+use chrono::{DateTime, Utc};
+use git2::{Oid, Repository, Sort};
+use std::fmt;
+
+/// Why a release counts as a hotfix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotfixReason {
+    /// Patch version bump, e.g. v1.4.2
+    PatchTag,
+    /// Ships a merge of a `hotfix/` branch
+    HotfixBranch,
+}
+
+impl fmt::Display for HotfixReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::PatchTag => "patch tag",
+            Self::HotfixBranch => "hotfix/ merge",
+        })
+    }
+}
+
+/// One release tag treated as a deployment
+#[derive(Debug, Clone)]
+pub struct Release {
+    pub tag: String,
+    pub hash: String,
+    /// Tagger date for annotated tags, otherwise the commit date
+    pub released_at: DateTime<Utc>,
+    /// Non-merge commits first shipped in this release
+    pub commits: usize,
+    pub churn: usize,
+    pub authors: Vec<String>,
+    /// Hours from each commit's author date to the release
+    pub lead_times: Vec<usize>,
+    pub hotfix: Option<HotfixReason>,
+}
+
+/// Releases, lead times and hotfixes for one period
+#[derive(Debug, Default, Clone)]
+pub struct DeliveryStats {
+    pub label: String,
+    pub releases: usize,
+    pub hotfixes: usize,
+    pub commits: usize,
+    pub churn: usize,
+    pub lead_times: Vec<usize>,
+}
+
+impl DeliveryStats {
+    pub fn median_lead_time(&self) -> usize {
+        median(&self.lead_times)
+    }
+
+    pub fn p90_lead_time(&self) -> usize {
+        percentile(&self.lead_times, 90)
+    }
+
+    /// Share of releases that were hotfixes, in percent
+    pub fn change_failure_rate(&self) -> usize {
+        (self.hotfixes * 100).checked_div(self.releases).unwrap_or(0)
+    }
+
+    /// Count releases and hotfixes and pool commit lead times per period, ordered by key
+    pub fn group(releases: &[Release], key: impl Fn(&Release) -> String) -> Vec<Self> {
+        let new = |label| Self {
+            label,
+            ..Default::default()
+        };
+        rollup(releases, key, new, |stats, release| {
+            stats.releases += 1;
+            stats.hotfixes += usize::from(release.hotfix.is_some());
+            stats.commits += release.commits;
+            stats.churn += release.churn;
+            stats.lead_times.extend(&release.lead_times);
+        })
+    }
+}
+
+/// Releases per week over the analyzed window
+///
+/// The window is the last `days` days, or from the first release to `now` when `days` is 0,
+/// so a lone release or two on the same day don't read as a weekly cadence.
+#[allow(clippy::cast_precision_loss)]
+pub fn releases_per_week(releases: &[Release], days: i64, now: DateTime<Utc>) -> f64 {
+    let window = if days > 0 {
+        days
+    } else {
+        releases
+            .iter()
+            .map(|release| (now - release.released_at).num_days())
+            .max()
+            .unwrap_or_default()
+    };
+    releases.len() as f64 * 7.0 / window.max(1) as f64
+}
+
+/// Whether a tag names a patch release: the last of three version numbers is non-zero
+pub fn is_patch_tag(tag: &str) -> bool {
+    let version = tag.trim_start_matches(|c: char| !c.is_ascii_digit());
+    // Ignore pre-release and build suffixes: 1.2.3-rc.1, 1.2.3+build
+    let version = version.split(['-', '+']).next().unwrap_or_default();
+    let parts: Vec<&str> = version.split('.').collect();
+    parts.len() == 3
+        && parts.iter().all(|part| part.parse::<u64>().is_ok())
+        && parts[2].parse::<u64>().is_ok_and(|patch| patch > 0)
+}
+
+/// Treat tags matching `pattern` (a glob such as `v*`) as deployments
+///
+/// Each release ships the commits reachable from its tag but not from any earlier release.
+pub fn analyze_delivery(
+    repo: &Repository,
+    days: i64,
+    pattern: &str,
+) -> Result<Vec<Release>, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut tags: Vec<(String, Oid, DateTime<Utc>)> = Vec::new();
+    for name in repo.tag_names(Some(pattern))?.iter().flatten() {
+        let object = repo.revparse_single(&format!("refs/tags/{name}"))?;
+        // Tags on a tree or blob are not deployments
+        let Ok(commit) = object.peel_to_commit() else {
+            continue;
+        };
+        let released_at = object
+            .as_tag()
+            .and_then(git2::Tag::tagger)
+            .map_or_else(|| commit.time(), |tagger| tagger.when());
+        tags.push((name.to_string(), commit.id(), time_to_datetime(released_at)));
+    }
+    tags.sort_by_key(|(_, _, released_at)| *released_at);
+
+    let mut releases = Vec::new();
+    let mut shipped: Vec<Oid> = Vec::new();
+    for (tag, oid, released_at) in tags {
+        let mut revwalk = repo.revwalk()?;
+        revwalk.push(oid)?;
+        for previous in &shipped {
+            revwalk.hide(*previous)?;
+        }
+        revwalk.set_sorting(Sort::TIME)?;
+        shipped.push(oid);
+
+        let mut release = Release {
+            hotfix: is_patch_tag(&tag).then_some(HotfixReason::PatchTag),
+            tag,
+            hash: oid.to_string()[..7].to_string(),
+            released_at,
+            commits: 0,
+            churn: 0,
+            authors: Vec::new(),
+            lead_times: Vec::new(),
+        };
+        for commit_oid in revwalk {
+            let commit = repo.find_commit(commit_oid?)?;
+            if commit.parent_count() > 1 {
+                let (_, branch) = parse_merge_message(commit.message().unwrap_or_default());
+                if branch.is_some_and(|b| b.starts_with("hotfix/")) {
+                    release.hotfix.get_or_insert(HotfixReason::HotfixBranch);
+                }
+                continue;
+            }
+            let author = canonical_author(&commit, mailmap.as_ref());
+            let name = author.name().unwrap_or("unknown").to_string();
+            if !release.authors.contains(&name) {
+                release.authors.push(name);
+            }
+            let (additions, deletions, _) = get_commit_stats(&commit, repo)?;
+            release.commits += 1;
+            release.churn += additions + deletions;
+            let lead_time = (released_at - time_to_datetime(author.when())).num_hours();
+            release.lead_times.push(usize::try_from(lead_time).unwrap_or(0));
+        }
+
+        // Earlier releases still count as shipped so later ones only see new commits
+        if is_within_days(git2::Time::new(released_at.timestamp(), 0), days) {
+            releases.push(release);
+        }
+    }
+
+    Ok(releases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn release(released_at: DateTime<Utc>) -> Release {
+        Release {
+            tag: String::new(),
+            hash: String::new(),
+            released_at,
+            commits: 0,
+            churn: 0,
+            authors: Vec::new(),
+            lead_times: Vec::new(),
+            hotfix: None,
+        }
+    }
+
+    #[test]
+    fn test_releases_per_week() {
+        let now = Utc::now();
+        // A single release four weeks ago is one release in four weeks, not one a week
+        let single = [release(now - Duration::days(28))];
+        assert!((releases_per_week(&single, 0, now) - 0.25).abs() < 1e-9);
+        assert!((releases_per_week(&single, 70, now) - 0.1).abs() < 1e-9);
+
+        // Two releases on the same day count against the whole window
+        let same_day = [
+            release(now - Duration::days(14)),
+            release(now - Duration::days(14) + Duration::hours(3)),
+        ];
+        assert!((releases_per_week(&same_day, 0, now) - 1.0).abs() < 1e-9);
+        assert!((releases_per_week(&same_day, 28, now) - 0.5).abs() < 1e-9);
+
+        assert!(releases_per_week(&[], 0, now).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_is_patch_tag() {
+        assert!(is_patch_tag("v1.4.2"));
+        assert!(is_patch_tag("release-2.0.1-rc.1"));
+        assert!(!is_patch_tag("v1.4.0"));
+        assert!(!is_patch_tag("v2.1"));
+        assert!(!is_patch_tag("nightly"));
+    }
+}
@@ -0,0 +1,58 @@
+/// How commits are grouped into work sessions for effort estimates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionOptions {
+    /// Commits closer together than this belong to the same session
+    pub gap_minutes: i64,
+    /// Time credited before a session's first commit, for work that led up to it
+    pub lead_in_minutes: i64,
+}
+
+impl Default for SessionOptions {
+    fn default() -> Self {
+        Self {
+            gap_minutes: 120,
+            lead_in_minutes: 120,
+        }
+    }
+}
+
+/// Sessions and estimated minutes of work behind commits at `timestamps` (seconds, any order)
+///
+/// Within a session the time between consecutive commits counts in full; each session also
+/// gets the lead-in for the work before its first commit.
+pub fn estimate_effort(timestamps: &[i64], options: SessionOptions) -> (usize, usize) {
+    let mut sorted = timestamps.to_vec();
+    sorted.sort_unstable();
+
+    let mut sessions = 0;
+    let mut seconds = 0;
+    for (idx, time) in sorted.iter().enumerate() {
+        let gap = idx.checked_sub(1).map(|prev| time - sorted[prev]);
+        match gap {
+            Some(gap) if gap <= options.gap_minutes * 60 => seconds += gap,
+            _ => {
+                sessions += 1;
+                seconds += options.lead_in_minutes * 60;
+            }
+        }
+    }
+    (sessions, usize::try_from(seconds / 60).unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_effort() {
+        let options = SessionOptions {
+            gap_minutes: 120,
+            lead_in_minutes: 30,
+        };
+        assert_eq!(estimate_effort(&[], options), (0, 0));
+        // 9:00, 9:45, 11:00 form one session; 18:00 starts another
+        let hour = 3600;
+        let commits = [18 * hour, 9 * hour, 9 * hour + 45 * 60, 11 * hour];
+        assert_eq!(estimate_effort(&commits, options), (2, 30 + 120 + 30));
+    }
+}
@@ -0,0 +1,98 @@
+use super::{files::walk_file_changes, time_to_datetime, ContributorStats, PathGrouper};
+use chrono::{DateTime, Utc};
+use git2::Repository;
+use std::collections::HashMap;
+
+/// A contributor ranked by how much, and how recently, they changed a path
+#[derive(Debug, Default, Clone)]
+pub struct ExpertStats {
+    pub contributor: ContributorStats,
+    pub score: f64,
+    pub last_change: Option<DateTime<Utc>>,
+}
+
+/// Whether a file lies at or below a path (a file or a directory)
+pub fn path_matches(file: &str, path: &str) -> bool {
+    let path = path.trim_start_matches("./").trim_end_matches('/');
+    path.is_empty() || file == path || file.starts_with(&format!("{path}/"))
+}
+
+/// Weight of a single change: larger changes count more, with diminishing returns,
+/// and the weight halves every `half_life_days`
+#[allow(clippy::cast_precision_loss)]
+pub fn change_weight(lines: usize, age_days: f64, half_life_days: f64) -> f64 {
+    let size = (lines as f64).ln_1p() + 1.0;
+    size * 0.5_f64.powf(age_days.max(0.0) / half_life_days)
+}
+
+/// Rank people by recent, size-weighted changes to files under `path`
+#[allow(clippy::cast_precision_loss)]
+pub fn analyze_experts(
+    repo: &Repository,
+    days: i64,
+    path: &str,
+    half_life_days: f64,
+) -> Result<Vec<ExpertStats>, git2::Error> {
+    let now = Utc::now();
+    let mut expert_map: HashMap<String, ExpertStats> = HashMap::new();
+
+    walk_file_changes(repo, days, &PathGrouper::default(), |commit, author, file_stats| {
+        let (mut additions, mut deletions, mut files) = (0, 0, 0);
+        for file in file_stats {
+            if path_matches(&file.path, path) {
+                additions += file.additions;
+                deletions += file.deletions;
+                files += 1;
+            }
+        }
+        if files == 0 {
+            return;
+        }
+
+        let email = author.email().unwrap_or("unknown").to_string();
+        let name = author.name().unwrap_or("unknown").to_string();
+        let committed_at = time_to_datetime(commit.time());
+        let age_days = (now - committed_at).num_seconds() as f64 / 86_400.0;
+
+        let entry = expert_map.entry(email.clone()).or_insert_with(|| ExpertStats {
+            contributor: ContributorStats {
+                name,
+                email,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        entry.contributor.commits += 1;
+        entry.contributor.additions += additions;
+        entry.contributor.deletions += deletions;
+        entry.contributor.files_changed += files;
+        entry.contributor.record_commit_time(committed_at);
+        entry.score += change_weight(additions + deletions, age_days, half_life_days);
+        entry.last_change = entry.last_change.max(Some(committed_at));
+    })?;
+
+    let mut experts: Vec<ExpertStats> = expert_map.into_values().collect();
+    experts.sort_by(|a, b| b.score.total_cmp(&a.score));
+    Ok(experts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_matches() {
+        assert!(path_matches("src/stats/mod.rs", "src"));
+        assert!(path_matches("src/stats/mod.rs", "./src/stats/"));
+        assert!(path_matches("src/main.rs", "src/main.rs"));
+        assert!(!path_matches("src2/main.rs", "src"));
+    }
+
+    #[test]
+    fn test_change_weight_decays() {
+        let fresh = change_weight(100, 0.0, 90.0);
+        let old = change_weight(100, 90.0, 90.0);
+        assert!((fresh / old - 2.0).abs() < 1e-9);
+        assert!(change_weight(1000, 0.0, 90.0) > change_weight(10, 0.0, 90.0));
+    }
+}
@@ -1,15 +1,63 @@
-use super::is_within_days;
-use git2::{DiffOptions, Repository};
-use std::collections::HashMap;
+use super::{canonical_author, get_file_line_stats, is_included, FileLineStats, PathGrouper};
+use git2::{Commit, Repository, Signature};
+use std::{cmp::Reverse, collections::HashMap};
 
 #[derive(Debug, Default, Clone)]
 pub struct FileStats {
     pub path: String,
     pub changes: usize,
+    pub additions: usize,
+    pub deletions: usize,
     pub contributors: Vec<String>,
 }
 
-pub fn analyze_file_changes(repo: &Repository, days: i64) -> Result<Vec<FileStats>, git2::Error> {
+impl FileStats {
+    /// Total lines changed (added + deleted)
+    pub const fn churn(&self) -> usize {
+        self.additions + self.deletions
+    }
+}
+
+/// Ranking order for file change statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileSort {
+    /// Number of commits touching the path
+    #[default]
+    Touches,
+    /// Lines added
+    Added,
+    /// Lines deleted
+    Deleted,
+    /// Lines added plus lines deleted
+    Churn,
+}
+
+pub fn sort_files(files: &mut [FileStats], sort: FileSort) {
+    match sort {
+        FileSort::Touches => files.sort_by_key(|f| Reverse((f.changes, f.churn()))),
+        FileSort::Added => files.sort_by_key(|f| Reverse((f.additions, f.changes))),
+        FileSort::Deleted => files.sort_by_key(|f| Reverse((f.deletions, f.changes))),
+        FileSort::Churn => files.sort_by_key(|f| Reverse((f.churn(), f.changes))),
+    }
+}
+
+pub fn analyze_file_changes(
+    repo: &Repository,
+    days: i64,
+    grouper: &PathGrouper,
+) -> Result<Vec<FileStats>, git2::Error> {
+    walk_file_changes(repo, days, grouper, |_, _, _| {})
+}
+
+/// Walk commits and aggregate per-path stats, handing each commit, its author (after
+/// .mailmap) and its per-file diff stats to `visit` for further analysis
+pub fn walk_file_changes(
+    repo: &Repository,
+    days: i64,
+    grouper: &PathGrouper,
+    mut visit: impl FnMut(&Commit, &Signature, &[FileLineStats]),
+) -> Result<Vec<FileStats>, git2::Error> {
+    let mailmap = repo.mailmap().ok();
     let mut file_map: HashMap<String, FileStats> = HashMap::new();
     let mut revwalk = repo.revwalk()?;
     // Analyze all branches, not just HEAD
@@ -19,46 +67,86 @@ pub fn analyze_file_changes(repo: &Repository, days: i64) -> Result<Vec<FileStat
         let oid = oid?;
         let commit = repo.find_commit(oid)?;
 
-        if !is_within_days(commit.time(), days) {
+        if !is_included(&commit, repo, days) {
             continue;
         }
 
-        let author_name = commit.author().name().unwrap_or("unknown").to_string();
-
-        let a = if commit.parent_count() > 0 {
-            let parent = commit.parent(0)?;
-            Some(parent.tree()?)
-        } else {
-            None
-        };
-        let b = commit.tree()?;
-
-        let mut diff_opts = DiffOptions::new();
-        let diff = repo.diff_tree_to_tree(a.as_ref(), Some(&b), Some(&mut diff_opts))?;
-
-        diff.foreach(
-            &mut |delta, _| {
-                if let Some(path) = delta.new_file().path() {
-                    let path_str = path.to_string_lossy().to_string();
-                    let entry = file_map.entry(path_str.clone()).or_insert_with(|| FileStats {
-                        path: path_str,
-                        changes: 0,
-                        contributors: Vec::new(),
-                    });
-                    entry.changes += 1;
-                    if !entry.contributors.contains(&author_name) {
-                        entry.contributors.push(author_name.clone());
-                    }
-                }
-                true
-            },
-            None,
-            None,
-            None,
-        )?;
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let author_name = author.name().unwrap_or("unknown").to_string();
+        let file_stats = get_file_line_stats(&commit, repo)?;
+
+        // A commit touching several files in one group counts as a single change
+        let mut touched: HashMap<String, (usize, usize)> = HashMap::new();
+        for file in &file_stats {
+            let lines = touched.entry(grouper.key(&file.path)).or_default();
+            lines.0 += file.additions;
+            lines.1 += file.deletions;
+        }
+
+        for (key, (additions, deletions)) in touched {
+            let entry = file_map.entry(key.clone()).or_insert_with(|| FileStats {
+                path: key,
+                ..Default::default()
+            });
+            entry.changes += 1;
+            entry.additions += additions;
+            entry.deletions += deletions;
+            if !entry.contributors.contains(&author_name) {
+                entry.contributors.push(author_name.clone());
+            }
+        }
+        visit(&commit, &author, &file_stats);
     }
 
     let mut files: Vec<FileStats> = file_map.into_values().collect();
-    files.sort_by(|a, b| b.changes.cmp(&a.changes));
+    sort_files(&mut files, FileSort::Touches);
     Ok(files)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, changes: usize, additions: usize, deletions: usize) -> FileStats {
+        FileStats {
+            path: path.to_string(),
+            changes,
+            additions,
+            deletions,
+            ..Default::default()
+        }
+    }
+
+    fn sorted(sort: FileSort) -> Vec<String> {
+        let mut files = vec![
+            file("a", 5, 10, 0),
+            file("b", 2, 1, 40),
+            file("c", 5, 30, 5),
+            file("d", 1, 30, 0),
+        ];
+        sort_files(&mut files, sort);
+        files.into_iter().map(|f| f.path).collect()
+    }
+
+    #[test]
+    fn test_sort_files_touches() {
+        // Equal touches fall back to churn
+        assert_eq!(sorted(FileSort::Touches), ["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    fn test_sort_files_added() {
+        // Equal additions fall back to touches
+        assert_eq!(sorted(FileSort::Added), ["c", "d", "a", "b"]);
+    }
+
+    #[test]
+    fn test_sort_files_deleted() {
+        assert_eq!(sorted(FileSort::Deleted), ["b", "c", "a", "d"]);
+    }
+
+    #[test]
+    fn test_sort_files_churn() {
+        assert_eq!(sorted(FileSort::Churn), ["b", "c", "d", "a"]);
+    }
+}
@@ -0,0 +1,172 @@
+use super::{canonical_author, formatting_line_counts, is_within_days};
+use git2::Repository;
+use std::{cmp::Reverse, collections::HashMap};
+
+/// Formatting churn for one contributor
+#[derive(Debug, Default, Clone)]
+pub struct FormattingStats {
+    pub name: String,
+    pub email: String,
+    pub commits: usize,
+    /// Commits that only change whitespace, line endings or blank lines
+    pub formatting_commits: usize,
+    /// Lines changed, counting whitespace-only changes
+    pub lines: usize,
+    /// Lines whose only change was whitespace, line endings or blank lines
+    pub formatting_lines: usize,
+}
+
+impl FormattingStats {
+    /// Share of changed lines that were formatting, in percent
+    pub fn formatting_share(&self) -> usize {
+        (self.formatting_lines * 100).checked_div(self.lines).unwrap_or(0)
+    }
+}
+
+/// A commit that only changes formatting
+#[derive(Debug, Clone)]
+pub struct FormattingCommit {
+    pub hash: String,
+    pub author: String,
+    pub email: String,
+    pub summary: String,
+    pub lines: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct FormattingReport {
+    pub contributors: Vec<FormattingStats>,
+    /// Largest first
+    pub commits: Vec<FormattingCommit>,
+}
+
+/// Compare every non-merge commit's line counts with and without whitespace-only changes
+pub fn analyze_formatting(repo: &Repository, days: i64) -> Result<FormattingReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut stats_map: HashMap<String, FormattingStats> = HashMap::new();
+    let mut commits = Vec::new();
+
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        // Formatting commits are what this report is about, so only the period applies here
+        if !is_within_days(commit.time(), days) || commit.parent_count() > 1 {
+            continue;
+        }
+
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let name = author.name().unwrap_or("unknown").to_string();
+        let email = author.email().unwrap_or("unknown").to_string();
+        let (lines, substantive) = formatting_line_counts(&commit, repo)?;
+        let formatting_only = lines > 0 && substantive == 0;
+
+        let entry = stats_map.entry(format!("{name}|{email}")).or_insert_with(|| FormattingStats {
+            name: name.clone(),
+            email: email.clone(),
+            ..Default::default()
+        });
+        entry.commits += 1;
+        entry.lines += lines;
+        entry.formatting_lines += lines.saturating_sub(substantive);
+
+        if formatting_only {
+            entry.formatting_commits += 1;
+            commits.push(FormattingCommit {
+                hash: oid.to_string()[..7].to_string(),
+                author: name,
+                email,
+                summary: commit.summary().unwrap_or_default().to_string(),
+                lines,
+            });
+        }
+    }
+
+    let mut contributors: Vec<FormattingStats> = stats_map.into_values().collect();
+    contributors.sort_by_key(|s| Reverse(s.formatting_lines));
+    commits.sort_by_key(|c| Reverse(c.lines));
+    Ok(FormattingReport {
+        contributors,
+        commits,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{
+        analyze_contributors, get_commit_stats, is_formatting_only,
+        tests::{commit_as, temp_repo, TEST_OPTIONS},
+        AnalysisOptions,
+    };
+    use git2::Oid;
+    use std::{error::Error, fs};
+
+    fn set_options(options: AnalysisOptions) {
+        TEST_OPTIONS.with(|test_options| test_options.set(Some(options)));
+    }
+
+    #[test]
+    fn test_formatting_only_commits() -> Result<(), Box<dyn Error>> {
+        let (dir, repo) = temp_repo("formatting")?;
+        let alice = ("Alice", "alice@example.com");
+        let versions: [(&str, &[u8]); 4] = [
+            ("Add main", b"fn main() {\n    let x = 1;\n}\n"),
+            ("Reindent", b"fn main() {\n        let x = 1;\n}\n"),
+            ("Add blank line", b"fn main() {\n\n        let x = 1;\n}\n"),
+            ("Convert to CRLF", b"fn main() {\r\n\r\n        let x = 1;\r\n}\r\n"),
+        ];
+        let mut oids: Vec<Oid> = Vec::new();
+        for (time, (message, content)) in (1_000..).zip(versions) {
+            let parents: Vec<Oid> = oids.last().copied().into_iter().collect();
+            oids.push(commit_as(&repo, alice, message, time, &parents, &[("main.rs", content)])?);
+        }
+        // Reindents one line and adds another
+        let content: &[u8] = b"fn main() {\r\n\r\n    let x = 1;\r\n    let y = 2;\r\n}\r\n";
+        let bob = ("Bob", "bob@example.com");
+        let real = commit_as(&repo, bob, "Add y", 2_000, &[oids[3]], &[("main.rs", content)])?;
+        oids.push(real);
+        repo.reference("refs/heads/main", real, true, "test")?;
+
+        let formatting_only = oids
+            .iter()
+            .map(|oid| is_formatting_only(&repo.find_commit(*oid)?, &repo))
+            .collect::<Result<Vec<bool>, git2::Error>>()?;
+        assert_eq!(formatting_only, [false, true, true, true, false]);
+
+        let report = analyze_formatting(&repo, 0)?;
+        assert_eq!(report.commits.len(), 3);
+        let alice_stats = report.contributors.iter().find(|s| s.name == "Alice");
+        assert_eq!(alice_stats.map(|s| s.formatting_commits), Some(3));
+
+        // Line counts shrink to the substantive change with --ignore-whitespace
+        let real = repo.find_commit(real)?;
+        assert_eq!(get_commit_stats(&real, &repo)?, (2, 1, 1));
+        set_options(AnalysisOptions {
+            ignore_whitespace: true,
+            ..Default::default()
+        });
+        assert_eq!(get_commit_stats(&real, &repo)?, (1, 0, 1));
+
+        // --exclude-formatting leaves the formatting-only commits out of contributor totals
+        set_options(AnalysisOptions {
+            exclude_formatting: true,
+            ..Default::default()
+        });
+        let mut commits: Vec<(String, usize)> = analyze_contributors(&repo, 0)?
+            .into_iter()
+            .map(|s| (s.name, s.commits))
+            .collect();
+        commits.sort();
+        assert_eq!(commits, [("Alice".to_string(), 1), ("Bob".to_string(), 1)]);
+
+        drop(real);
+        drop(repo);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,382 @@
+use git2::{ObjectType, Repository, TreeWalkMode, TreeWalkResult};
+use std::{collections::BTreeMap, str::FromStr};
+
+/// Label used for paths that sit at the repository root
+const ROOT_GROUP: &str = ".";
+
+/// How file paths are rolled up before aggregation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GroupBy {
+    /// One group per file path
+    #[default]
+    File,
+    /// One group per directory, truncated to the given depth
+    Dir(usize),
+    /// One group per module: a Cargo or npm workspace member, or a directory with a go.mod
+    Module,
+}
+
+impl FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, depth) = match s.split_once(':') {
+            Some((kind, depth)) => (kind, Some(depth)),
+            None => (s, None),
+        };
+
+        match (kind, depth) {
+            ("file", None) => Ok(Self::File),
+            ("module", None) => Ok(Self::Module),
+            ("dir", None) => Ok(Self::Dir(1)),
+            ("dir", Some(depth)) => match depth.parse() {
+                Ok(depth) if depth > 0 => Ok(Self::Dir(depth)),
+                _ => Err(format!("invalid directory depth '{depth}' (expected a number >= 1)")),
+            },
+            _ => Err(format!("invalid group '{s}' (expected file, dir[:depth] or module)")),
+        }
+    }
+}
+
+/// Maps file paths to the group they roll up into
+#[derive(Debug, Clone, Default)]
+pub struct PathGrouper {
+    group_by: GroupBy,
+    /// Module root directories, longest first so the innermost module wins
+    module_roots: Vec<String>,
+}
+
+impl PathGrouper {
+    pub fn new(repo: &Repository, group_by: GroupBy) -> Result<Self, git2::Error> {
+        let module_roots = if group_by == GroupBy::Module {
+            detect_module_roots(repo)?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            group_by,
+            module_roots,
+        })
+    }
+
+    /// Group key for a repository-relative file path
+    pub fn key(&self, path: &str) -> String {
+        match self.group_by {
+            GroupBy::File => path.to_string(),
+            GroupBy::Dir(depth) => {
+                let dirs: Vec<&str> = path.split('/').collect();
+                let dirs = &dirs[..dirs.len() - 1];
+                if dirs.is_empty() {
+                    ROOT_GROUP.to_string()
+                } else {
+                    format!("{}/", dirs[..depth.min(dirs.len())].join("/"))
+                }
+            }
+            GroupBy::Module => self
+                .module_roots
+                .iter()
+                .find(|root| path.starts_with(&format!("{root}/")))
+                .map_or_else(|| ROOT_GROUP.to_string(), |root| format!("{root}/")),
+        }
+    }
+}
+
+/// Fold `items` into one entry per `key`, ordered by key
+///
+/// `new` starts the entry for a key from its label and `add` folds each item into it.
+pub fn rollup<T, S>(
+    items: &[T],
+    key: impl Fn(&T) -> String,
+    new: impl Fn(String) -> S,
+    mut add: impl FnMut(&mut S, &T),
+) -> Vec<S> {
+    let mut map: BTreeMap<String, S> = BTreeMap::new();
+    for item in items {
+        let label = key(item);
+        let entry = map.entry(label.clone()).or_insert_with(|| new(label));
+        add(entry, item);
+    }
+    map.into_values().collect()
+}
+
+/// Member directories declared by a root workspace manifest
+#[derive(Debug, Default)]
+struct Workspace {
+    /// Manifest each member directory contains
+    manifest: &'static str,
+    members: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl Workspace {
+    /// `[workspace]` `members` and `exclude` of a root Cargo.toml
+    fn cargo(manifest: &str) -> Self {
+        Self {
+            manifest: "Cargo.toml",
+            members: toml_array(manifest, "workspace", "members"),
+            exclude: toml_array(manifest, "workspace", "exclude"),
+        }
+    }
+
+    /// `workspaces` of a root package.json, as a list or under `packages`; `!` excludes
+    fn npm(manifest: &str) -> Self {
+        let json: serde_json::Value = serde_json::from_str(manifest).unwrap_or_default();
+        let workspaces = &json["workspaces"];
+        let globs = workspaces.as_array().or_else(|| workspaces["packages"].as_array());
+        let mut workspace = Self {
+            manifest: "package.json",
+            ..Self::default()
+        };
+        for glob in globs.into_iter().flatten().filter_map(serde_json::Value::as_str) {
+            match glob.strip_prefix('!') {
+                Some(excluded) => workspace.exclude.push(excluded.to_string()),
+                None => workspace.members.push(glob.to_string()),
+            }
+        }
+        workspace
+    }
+
+    fn contains(&self, dir: &str) -> bool {
+        self.members.iter().any(|glob| glob_matches(glob, dir))
+            && !self.exclude.iter().any(|glob| glob_matches(glob, dir))
+    }
+}
+
+/// String values of `key = [...]` in a TOML `[table]`, which may span several lines
+fn toml_array(text: &str, table: &str, key: &str) -> Vec<String> {
+    let header = format!("[{table}]");
+    let mut lines = text
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .skip_while(|line| *line != header)
+        .skip(1)
+        .take_while(|line| !line.starts_with('['));
+    let Some(first) = lines.by_ref().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        (name.trim() == key).then_some(value)
+    }) else {
+        return Vec::new();
+    };
+
+    let mut array = first.to_string();
+    for line in lines {
+        if array.contains(']') {
+            break;
+        }
+        array.push_str(line);
+    }
+    let array = array.split(']').next().unwrap_or_default();
+    array
+        .split(',')
+        .map(|item| item.trim().trim_start_matches('[').trim().trim_matches(['"', '\'']))
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether a directory matches a workspace glob: `*` and `?` within a segment, `**` across them
+fn glob_matches(glob: &str, dir: &str) -> bool {
+    fn segments_match(glob: &[&str], dir: &[&str]) -> bool {
+        match (glob.split_first(), dir.split_first()) {
+            (None, None) => true,
+            (Some((&"**", rest)), _) => {
+                segments_match(rest, dir) || (!dir.is_empty() && segments_match(glob, &dir[1..]))
+            }
+            (Some((pattern, rest)), Some((name, dir_rest))) => {
+                wildcard_matches(pattern, name) && segments_match(rest, dir_rest)
+            }
+            _ => false,
+        }
+    }
+
+    let glob: Vec<&str> = glob.trim_start_matches("./").trim_end_matches('/').split('/').collect();
+    let dir: Vec<&str> = dir.split('/').collect();
+    segments_match(&glob, &dir)
+}
+
+/// Match one path segment against a pattern with `*` (any run) and `?` (any character)
+fn wildcard_matches(pattern: &str, text: &str) -> bool {
+    let (pattern, text): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), text.chars().collect());
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` and the text position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p + 1, t));
+            p += 1;
+        } else if let Some((star, start)) = backtrack {
+            // Let the last `*` swallow one more character
+            backtrack = Some((star, start + 1));
+            p = star;
+            t = start + 1;
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Find module roots in the HEAD tree
+///
+/// Cargo and npm packages count when the root Cargo.toml `[workspace]` or package.json
+/// `workspaces` list them, so fixture and example manifests don't split the tree. Go has no
+/// root manifest to read, so every `go.mod` outside `testdata` is a module.
+fn detect_module_roots(repo: &Repository) -> Result<Vec<String>, git2::Error> {
+    // An unborn HEAD has no modules yet
+    let Ok(head) = repo.head() else {
+        return Ok(Vec::new());
+    };
+    let tree = head.peel_to_tree()?;
+
+    let root_manifest = |name: &str| {
+        tree.get_name(name)
+            .and_then(|entry| entry.to_object(repo).ok()?.peel_to_blob().ok())
+            .map(|blob| String::from_utf8_lossy(blob.content()).into_owned())
+    };
+    let workspaces = [
+        root_manifest("Cargo.toml").map(|text| Workspace::cargo(&text)),
+        root_manifest("package.json").map(|text| Workspace::npm(&text)),
+    ];
+
+    let mut roots = Vec::new();
+    tree.walk(TreeWalkMode::PreOrder, |dir, entry| {
+        let name = entry.name().unwrap_or_default();
+        // Third-party code and test data are not part of the repository's own module layout
+        if entry.kind() == Some(ObjectType::Tree)
+            && matches!(name, "node_modules" | "vendor" | "testdata")
+        {
+            return TreeWalkResult::Skip;
+        }
+        // Manifests at the top level describe the repository root, not a sub-module
+        if entry.kind() != Some(ObjectType::Blob) || dir.is_empty() {
+            return TreeWalkResult::Ok;
+        }
+        let root = dir.trim_end_matches('/');
+        let is_module = if name == "go.mod" {
+            true
+        } else {
+            workspaces
+                .iter()
+                .flatten()
+                .any(|workspace| workspace.manifest == name && workspace.contains(root))
+        };
+        if is_module && !roots.iter().any(|known| known == root) {
+            roots.push(root.to_string());
+        }
+        TreeWalkResult::Ok
+    })?;
+
+    roots.sort_by_key(|root| std::cmp::Reverse(root.len()));
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::tests::{commit, temp_repo};
+    use std::{error::Error, fs};
+
+    #[test]
+    fn test_rollup() {
+        let items = [("b", 1), ("a", 2), ("b", 3)];
+        let totals = rollup(
+            &items,
+            |(key, _)| (*key).to_string(),
+            |label| (label, 0),
+            |total, item| {
+                total.1 += item.1;
+            },
+        );
+        assert_eq!(totals, [("a".to_string(), 2), ("b".to_string(), 4)]);
+    }
+
+    #[test]
+    fn test_parse_group_by() {
+        assert_eq!("file".parse(), Ok(GroupBy::File));
+        assert_eq!("dir".parse(), Ok(GroupBy::Dir(1)));
+        assert_eq!("dir:3".parse(), Ok(GroupBy::Dir(3)));
+        assert_eq!("module".parse(), Ok(GroupBy::Module));
+        assert!("dir:0".parse::<GroupBy>().is_err());
+        assert!("folder".parse::<GroupBy>().is_err());
+    }
+
+    #[test]
+    fn test_dir_key() {
+        let grouper = PathGrouper {
+            group_by: GroupBy::Dir(2),
+            module_roots: Vec::new(),
+        };
+        assert_eq!(grouper.key("README.md"), ".");
+        assert_eq!(grouper.key("src/main.rs"), "src/");
+        assert_eq!(grouper.key("src/stats/files/mod.rs"), "src/stats/");
+    }
+
+    #[test]
+    fn test_module_key() {
+        let grouper = PathGrouper {
+            group_by: GroupBy::Module,
+            module_roots: vec!["crates/core/sub".to_string(), "crates/core".to_string()],
+        };
+        assert_eq!(grouper.key("crates/core/src/lib.rs"), "crates/core/");
+        assert_eq!(grouper.key("crates/core/sub/lib.rs"), "crates/core/sub/");
+        assert_eq!(grouper.key("crates/corex/lib.rs"), ".");
+        assert_eq!(grouper.key("docs/index.md"), ".");
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("crates/*", "crates/core"));
+        assert!(glob_matches("./tools/cli/", "tools/cli"));
+        assert!(glob_matches("packages/ui-*", "packages/ui-kit"));
+        assert!(glob_matches("libs/**", "libs/a/b"));
+        assert!(!glob_matches("crates/*", "crates/core/tests"));
+        assert!(!glob_matches("crates/?", "crates/core"));
+    }
+
+    #[test]
+    fn test_module_roots_from_workspaces() -> Result<(), Box<dyn Error>> {
+        let (dir, repo) = temp_repo("modules")?;
+        let cargo: &[u8] = b"[package]\nname = \"app\"\n\n[workspace]\n\
+            members = [\n  \"crates/*\", # all crates\n  'tools/cli',\n]\n\
+            exclude = [\"crates/legacy\"]\n\n[dependencies]\n";
+        let npm: &[u8] = br#"{"private": true, "workspaces": {"packages": ["web/*", "!web/old"]}}"#;
+        let manifest: &[u8] = b"";
+        let head = commit(
+            &repo,
+            0,
+            &[],
+            &[
+                ("Cargo.toml", cargo),
+                ("package.json", npm),
+                ("crates/core/Cargo.toml", manifest),
+                ("crates/core/tests/fixture/Cargo.toml", manifest),
+                ("crates/legacy/Cargo.toml", manifest),
+                ("tools/cli/Cargo.toml", manifest),
+                ("examples/demo/Cargo.toml", manifest),
+                ("web/app/package.json", manifest),
+                ("web/app/node_modules/left-pad/package.json", manifest),
+                ("web/old/package.json", manifest),
+                ("services/api/go.mod", manifest),
+                ("services/api/testdata/mod/go.mod", manifest),
+            ],
+        )?;
+        repo.reference("refs/heads/main", head, true, "test")?;
+        repo.set_head("refs/heads/main")?;
+
+        let mut roots = detect_module_roots(&repo)?;
+        roots.sort();
+        assert_eq!(roots, ["crates/core", "services/api", "tools/cli", "web/app"]);
+
+        let grouper = PathGrouper::new(&repo, GroupBy::Module)?;
+        assert_eq!(grouper.key("crates/core/tests/fixture/src/lib.rs"), "crates/core/");
+        assert_eq!(grouper.key("examples/demo/src/main.rs"), ROOT_GROUP);
+
+        drop(repo);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use super::{
+    language::{count_lines, LanguageDetector},
+    time_to_datetime, GroupBy, Interval, PathGrouper,
+};
+use chrono::{DateTime, Utc};
+use git2::{Commit, Delta, ObjectType, Oid, Repository, Sort, TreeWalkMode, TreeWalkResult};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Which commits the tree is sampled at
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SampleBy {
+    /// Every tag, in commit order
+    Tag,
+    /// The last first-parent commit of each month
+    #[default]
+    Month,
+    /// Every N first-parent commits
+    Commits,
+}
+
+/// Size of the codebase at one commit
+#[derive(Debug, Clone, Serialize)]
+pub struct GrowthSample {
+    /// Tag name, month or short hash
+    pub label: String,
+    pub hash: String,
+    pub date: DateTime<Utc>,
+    pub files: usize,
+    /// Lines in text files
+    pub lines: usize,
+    /// Uncompressed size of the commits and file versions reachable from the sample, in bytes
+    pub bytes: u64,
+    pub languages: BTreeMap<String, usize>,
+    /// Lines per top-level directory
+    pub directories: BTreeMap<String, usize>,
+}
+
+/// Commits along the first-parent chain of `tip`, oldest first
+fn first_parent_history<'r>(
+    repo: &'r Repository,
+    tip: &Commit,
+) -> Result<Vec<Commit<'r>>, git2::Error> {
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(tip.id())?;
+    revwalk.simplify_first_parent()?;
+    revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+    revwalk.map(|oid| repo.find_commit(oid?)).collect()
+}
+
+/// Pick the commits to sample, oldest first, with their labels
+fn sample_points<'r>(
+    repo: &'r Repository,
+    branch: &str,
+    sample_by: SampleBy,
+    every: usize,
+) -> Result<Vec<(String, Commit<'r>)>, git2::Error> {
+    if sample_by == SampleBy::Tag {
+        let mut points = Vec::new();
+        for name in repo.tag_names(None)?.iter().flatten() {
+            // Tags on a tree or blob mark no point in history
+            let Ok(commit) = repo.revparse_single(&format!("refs/tags/{name}"))?.peel_to_commit()
+            else {
+                continue;
+            };
+            points.push((name.to_string(), commit));
+        }
+        points.sort_by_key(|(_, commit)| commit.time().seconds());
+        return Ok(points);
+    }
+
+    let tip = repo.revparse_single(branch)?.peel_to_commit()?;
+    let history = first_parent_history(repo, &tip)?;
+    let last = history.len().saturating_sub(1);
+    let mut points: Vec<(String, Commit)> = Vec::new();
+
+    for (idx, commit) in history.into_iter().enumerate() {
+        match sample_by {
+            SampleBy::Month => {
+                // Later commits in the same month replace earlier ones
+                let month = Interval::Month.bucket(time_to_datetime(commit.time()));
+                if points.last().is_some_and(|(label, _)| *label == month) {
+                    points.pop();
+                }
+                points.push((month, commit));
+            }
+            // Always include the tip so the series ends at the current size
+            _ if idx % every.max(1) == 0 || idx == last => {
+                points.push((commit.id().to_string()[..7].to_string(), commit));
+            }
+            _ => {}
+        }
+    }
+    Ok(points)
+}
+
+/// Sample file count, lines of code and object size at tags, month ends or every N commits
+///
+/// Month and commit samples follow the first-parent history of `branch`. Line counts are
+/// cached per blob, so unchanged files are only read once across samples.
+pub fn analyze_growth(
+    repo: &Repository,
+    branch: &str,
+    sample_by: SampleBy,
+    every: usize,
+) -> Result<Vec<GrowthSample>, git2::Error> {
+    let odb = repo.odb()?;
+    let grouper = PathGrouper::new(repo, GroupBy::Dir(1))?;
+    let mut detector = LanguageDetector::new(repo);
+    // `None` for binary files
+    let mut line_cache: HashMap<Oid, Option<usize>> = HashMap::new();
+    let mut seen_blobs: HashSet<Oid> = HashSet::new();
+    let mut bytes = 0;
+    let mut previous: Vec<Oid> = Vec::new();
+    let mut samples = Vec::new();
+
+    for (label, commit) in sample_points(repo, branch, sample_by, every)? {
+        // Objects new since the previous samples
+        let mut revwalk = repo.revwalk()?;
+        revwalk.push(commit.id())?;
+        for oid in &previous {
+            revwalk.hide(*oid)?;
+        }
+        for oid in revwalk {
+            let new_commit = repo.find_commit(oid?)?;
+            bytes += odb.read_header(new_commit.id()).map_or(0, |(size, _)| size as u64);
+            let parent_tree = new_commit.parent(0).ok().map(|p| p.tree()).transpose()?;
+            let diff =
+                repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&new_commit.tree()?), None)?;
+            for delta in diff.deltas() {
+                let blob = delta.new_file().id();
+                if delta.status() != Delta::Deleted && seen_blobs.insert(blob) {
+                    bytes += odb.read_header(blob).map_or(0, |(size, _)| size as u64);
+                }
+            }
+        }
+        previous.push(commit.id());
+
+        let mut sample = GrowthSample {
+            label,
+            hash: commit.id().to_string()[..7].to_string(),
+            date: time_to_datetime(commit.time()),
+            files: 0,
+            lines: 0,
+            bytes,
+            languages: BTreeMap::new(),
+            directories: BTreeMap::new(),
+        };
+        commit.tree()?.walk(TreeWalkMode::PreOrder, |dir, entry| {
+            if entry.kind() != Some(ObjectType::Blob) {
+                return TreeWalkResult::Ok;
+            }
+            sample.files += 1;
+            let lines = *line_cache.entry(entry.id()).or_insert_with(|| {
+                repo.find_blob(entry.id())
+                    .ok()
+                    .filter(|blob| !blob.is_binary())
+                    .map(|blob| count_lines(blob.content()))
+            });
+            let Some(lines) = lines else {
+                return TreeWalkResult::Ok;
+            };
+
+            let path = format!("{dir}{}", entry.name().unwrap_or_default());
+            sample.lines += lines;
+            *sample.languages.entry(detector.detect(&path, Some(entry.id()))).or_insert(0) += lines;
+            *sample.directories.entry(grouper.key(&path)).or_insert(0) += lines;
+            TreeWalkResult::Ok
+        })?;
+        samples.push(sample);
+    }
+
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::tests::{commit, temp_repo};
+    use std::{error::Error, fs};
+
+    #[test]
+    fn test_tag_samples_skip_non_commits() -> Result<(), Box<dyn Error>> {
+        let (dir, repo) = temp_repo("growth-tags")?;
+        let first = commit(&repo, 100, &[], &[("a.txt", b"a\n")])?;
+        let second = commit(&repo, 200, &[first], &[("a.txt", b"a\nb\n")])?;
+        repo.reference("refs/tags/v2", second, false, "test")?;
+        repo.reference("refs/tags/v1", first, false, "test")?;
+        // A tag on a blob, like the public keys some projects publish
+        let key = repo.blob(b"public key")?;
+        repo.reference("refs/tags/signing-key", key, false, "test")?;
+
+        let points = sample_points(&repo, "HEAD", SampleBy::Tag, 0)?;
+        let labels: Vec<&str> = points.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(labels, ["v1", "v2"]);
+
+        drop(points);
+        drop(repo);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
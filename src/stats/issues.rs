@@ -0,0 +1,357 @@
+use super::{contributor::walk_contributors, ContributorStats};
+use git2::{Oid, Repository};
+use regex::Regex;
+use serde::Serialize;
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+/// Placeholder for the issue id in URL templates
+const ID_PLACEHOLDER: &str = "{id}";
+
+/// GitHub references (`GH-789`, `#456`) and Jira-style keys (`ABC-123`)
+///
+/// `GH-` comes before the generic key pattern so it is read as GitHub issue 789.
+pub const DEFAULT_ISSUE_PATTERNS: [&str; 3] = [
+    r"\bGH-(\d+)\b",
+    r"\b[A-Z][A-Z0-9]+-\d+\b",
+    r"(?:^|[^\w&/])#(\d+)\b",
+];
+
+/// Prefixes of standards and algorithms that look like Jira keys (`UTF-8`, `SHA-256`),
+/// ignored by the default patterns
+const NON_ISSUE_PREFIXES: [&str; 10] = [
+    "UTF", "UCS", "SHA", "ISO", "RFC", "CVE", "CWE", "PEP", "ECMA", "AES",
+];
+
+/// An issue tracker reference found in a commit message or branch name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueRef {
+    /// Reference as written, e.g. `ABC-123` or `#456`
+    pub key: String,
+    /// Link to the issue, when an issue URL template is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Finds issue references using a set of regex patterns
+///
+/// When a pattern has a capture group, the first group is the issue id used in URLs
+/// (e.g. `456` for `#456`); otherwise the whole match is. Patterns are tried in order and
+/// a match overlapping an earlier one is dropped.
+#[derive(Debug, Clone)]
+pub struct IssueMatcher {
+    patterns: Vec<Regex>,
+    /// Key prefixes (before the `-`) that are never issues
+    excluded_prefixes: &'static [&'static str],
+    /// URL template with an `{id}` placeholder
+    url_template: Option<String>,
+}
+
+impl Default for IssueMatcher {
+    fn default() -> Self {
+        Self {
+            patterns: DEFAULT_ISSUE_PATTERNS
+                .iter()
+                .filter_map(|pattern| Regex::new(pattern).ok())
+                .collect(),
+            excluded_prefixes: &NON_ISSUE_PREFIXES,
+            url_template: None,
+        }
+    }
+}
+
+impl IssueMatcher {
+    /// Match `patterns` (the defaults when empty), linking matches through `url_template`
+    pub fn new(patterns: &[String], url_template: Option<String>) -> Result<Self, regex::Error> {
+        let mut matcher = Self {
+            url_template,
+            ..Self::default()
+        };
+        // Custom patterns are taken as written
+        if !patterns.is_empty() {
+            matcher.patterns =
+                patterns.iter().map(|pattern| Regex::new(pattern)).collect::<Result<_, _>>()?;
+            matcher.excluded_prefixes = &[];
+        }
+        Ok(matcher)
+    }
+
+    /// All distinct issue references in `text`, in order of appearance
+    pub fn find(&self, text: &str) -> Vec<IssueRef> {
+        let mut found: Vec<(Range<usize>, IssueRef)> = Vec::new();
+        for pattern in &self.patterns {
+            for captures in pattern.captures_iter(text) {
+                let (Some(whole), id) = (captures.get(0), captures.get(1)) else {
+                    continue;
+                };
+                let overlaps = found
+                    .iter()
+                    .any(|(span, _)| span.start < whole.end() && whole.start() < span.end);
+                if overlaps {
+                    continue;
+                }
+                let id = id.unwrap_or(whole).as_str();
+                let key = if id == whole.as_str() {
+                    id.to_string()
+                } else {
+                    format!("#{id}")
+                };
+                let excluded = key
+                    .split_once('-')
+                    .is_some_and(|(prefix, _)| self.excluded_prefixes.contains(&prefix));
+                if excluded || found.iter().any(|(_, issue)| issue.key == key) {
+                    continue;
+                }
+                let url =
+                    self.url_template.as_ref().map(|template| template.replace(ID_PLACEHOLDER, id));
+                found.push((whole.range(), IssueRef { key, url }));
+            }
+        }
+        found.sort_by_key(|(span, _)| span.start);
+        found.into_iter().map(|(_, issue)| issue).collect()
+    }
+}
+
+/// Commits and churn attributed to one ticket
+#[derive(Debug, Clone)]
+pub struct TicketStats {
+    pub issue: IssueRef,
+    pub commits: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Emails of everyone with a commit referencing the ticket
+    pub contributors: Vec<String>,
+}
+
+impl TicketStats {
+    pub const fn churn(&self) -> usize {
+        self.additions + self.deletions
+    }
+}
+
+/// How consistently one contributor references tickets
+#[derive(Debug, Clone)]
+pub struct ContributorIssues {
+    pub contributor: ContributorStats,
+    /// Commits referencing at least one ticket
+    pub referenced: usize,
+    /// Distinct tickets referenced
+    pub tickets: usize,
+}
+
+impl ContributorIssues {
+    pub const fn unreferenced(&self) -> usize {
+        self.contributor.commits - self.referenced
+    }
+}
+
+/// Ticket traceability for a period
+#[derive(Debug, Default)]
+pub struct IssueReport {
+    /// Sorted by churn, largest first
+    pub tickets: Vec<TicketStats>,
+    pub contributors: Vec<ContributorIssues>,
+}
+
+/// Tickets named by branches, for the commits those branches contributed
+///
+/// Covers branches that still exist (commits not reachable from HEAD) and branches already
+/// merged, through the merge message (`Merge branch 'feature/ABC-1'`).
+fn branch_issue_refs(
+    repo: &Repository,
+    matcher: &IssueMatcher,
+) -> Result<HashMap<Oid, Vec<IssueRef>>, git2::Error> {
+    let mut refs: HashMap<Oid, Vec<IssueRef>> = HashMap::new();
+    let mut attribute = |revwalk: git2::Revwalk, issues: &[IssueRef]| -> Result<(), git2::Error> {
+        for oid in revwalk {
+            let entry = refs.entry(oid?).or_default();
+            for issue in issues {
+                if !entry.contains(issue) {
+                    entry.push(issue.clone());
+                }
+            }
+        }
+        Ok(())
+    };
+    let head = repo.head().ok().and_then(|head| head.target());
+
+    for branch in repo.branches(None)? {
+        let (branch, _) = branch?;
+        let (Some(name), Some(tip)) = (branch.name()?, branch.get().target()) else {
+            continue;
+        };
+        let issues = matcher.find(name);
+        if issues.is_empty() {
+            continue;
+        }
+        let mut revwalk = repo.revwalk()?;
+        revwalk.push(tip)?;
+        if let Some(head) = head {
+            revwalk.hide(head)?;
+        }
+        attribute(revwalk, &issues)?;
+    }
+
+    // Merged ranges (merged tip, mainline parent), so a range merged more than once, e.g.
+    // into several branches, is walked once with all of its tickets
+    let mut ranges: HashMap<(Oid, Oid), Vec<IssueRef>> = HashMap::new();
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_glob("refs/*")?;
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.parent_count() < 2 {
+            continue;
+        }
+        let issues = matcher.find(commit.summary().unwrap_or_default());
+        if issues.is_empty() {
+            continue;
+        }
+        let range = ranges.entry((commit.parent_id(1)?, commit.parent_id(0)?)).or_default();
+        for issue in issues {
+            if !range.contains(&issue) {
+                range.push(issue);
+            }
+        }
+    }
+    for ((tip, mainline), issues) in ranges {
+        // Commits brought in by the merged branch
+        let mut merged = repo.revwalk()?;
+        merged.push(tip)?;
+        merged.hide(mainline)?;
+        attribute(merged, &issues)?;
+    }
+
+    Ok(refs)
+}
+
+/// Tickets referenced by commit messages and branch names, in the same walk as
+/// `analyze_contributors`; a commit's full churn counts toward every ticket it references
+pub fn analyze_issues(
+    repo: &Repository,
+    days: i64,
+    matcher: &IssueMatcher,
+) -> Result<IssueReport, git2::Error> {
+    let branch_refs = branch_issue_refs(repo, matcher)?;
+    let mut tickets: HashMap<String, TicketStats> = HashMap::new();
+    // Per contributor key: (referenced commits, distinct tickets)
+    let mut referencing: HashMap<String, (usize, HashSet<String>)> = HashMap::new();
+
+    let contributors = walk_contributors(repo, days, |commit, key, (additions, deletions)| {
+        let mut issues = matcher.find(commit.message().unwrap_or_default());
+        for issue in branch_refs.get(&commit.id()).into_iter().flatten() {
+            if !issues.contains(issue) {
+                issues.push(issue.clone());
+            }
+        }
+        if issues.is_empty() {
+            return;
+        }
+
+        let email = key.rsplit_once('|').map_or(key, |(_, email)| email).to_string();
+        let (referenced, keys) = referencing.entry(key.to_string()).or_default();
+        *referenced += 1;
+        for issue in issues {
+            keys.insert(issue.key.clone());
+            let ticket = tickets.entry(issue.key.clone()).or_insert_with(|| TicketStats {
+                issue,
+                commits: 0,
+                additions: 0,
+                deletions: 0,
+                contributors: Vec::new(),
+            });
+            ticket.commits += 1;
+            ticket.additions += additions;
+            ticket.deletions += deletions;
+            if !ticket.contributors.contains(&email) {
+                ticket.contributors.push(email.clone());
+            }
+        }
+    })?;
+
+    let contributors = contributors
+        .into_iter()
+        .map(|contributor| {
+            let key = format!("{}|{}", contributor.name, contributor.email);
+            let (referenced, keys) = referencing.remove(&key).unwrap_or_default();
+            ContributorIssues {
+                contributor,
+                referenced,
+                tickets: keys.len(),
+            }
+        })
+        .collect();
+
+    let mut tickets: Vec<TicketStats> = tickets.into_values().collect();
+    tickets.sort_by_key(|t| (Reverse(t.churn()), Reverse(t.commits), t.issue.key.clone()));
+    Ok(IssueReport {
+        tickets,
+        contributors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(matcher: &IssueMatcher, text: &str) -> Vec<String> {
+        matcher.find(text).into_iter().map(|issue| issue.key).collect()
+    }
+
+    #[test]
+    fn test_find_default_patterns() {
+        let matcher = IssueMatcher::default();
+        assert_eq!(keys(&matcher, "fix: crash ABC-123 (#45)"), vec!["ABC-123", "#45"]);
+        assert_eq!(keys(&matcher, "#1 and #1 again"), vec!["#1"]);
+        assert!(keys(&matcher, "color: #fff, utf-8, a&#39;b").is_empty());
+    }
+
+    #[test]
+    fn test_find_github_keys() {
+        let matcher = IssueMatcher {
+            url_template: Some("https://github.com/o/r/issues/{id}".to_string()),
+            ..IssueMatcher::default()
+        };
+        let issues = matcher.find("GH-789: docs, see #789 and ABC-1");
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].key, "#789");
+        assert_eq!(issues[0].url.as_deref(), Some("https://github.com/o/r/issues/789"));
+        assert_eq!(issues[1].key, "ABC-1");
+    }
+
+    #[test]
+    fn test_find_skips_standards() {
+        let matcher = IssueMatcher::default();
+        let text = "Read UTF-8, hash with SHA-256, ISO-8601 dates, X-1 (RFC-7231, ABC-9)";
+        assert_eq!(keys(&matcher, text), vec!["ABC-9"]);
+        // Custom patterns are not filtered
+        let custom = IssueMatcher::new(&[r"\bSHA-\d+\b".to_string()], None)
+            .map(|matcher| keys(&matcher, "SHA-256"));
+        assert_eq!(custom, Ok(vec!["SHA-256".to_string()]));
+    }
+
+    #[test]
+    fn test_issue_urls() {
+        let matcher = IssueMatcher {
+            url_template: Some("https://tracker/{id}".to_string()),
+            ..IssueMatcher::default()
+        };
+        let issues = matcher.find("ABC-1 #2");
+        assert_eq!(issues[0].url.as_deref(), Some("https://tracker/ABC-1"));
+        assert_eq!(issues[1].url.as_deref(), Some("https://tracker/2"));
+    }
+
+    #[test]
+    fn test_custom_patterns() {
+        let patterns = vec![
+            r"\bPROJ-\d+\b".to_string(),
+            r"(?i)\bissue (\d+)".to_string(),
+        ];
+        let found = IssueMatcher::new(&patterns, None)
+            .map(|matcher| keys(&matcher, "PROJ-7, Issue 8, ABC-1 #9"));
+        assert_eq!(found, Ok(vec!["PROJ-7".to_string(), "#8".to_string()]));
+        assert!(IssueMatcher::new(&["(".to_string()], None).is_err());
+    }
+}
@@ -0,0 +1,396 @@
+use super::{canonical_author, get_file_line_stats, is_included};
+use git2::{AttrCheckFlags, AttrValue, ObjectType, Oid, Repository, TreeWalkMode, TreeWalkResult};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+/// Language reported for files no rule recognizes
+pub const UNKNOWN_LANGUAGE: &str = "Other";
+
+/// File extensions (lowercase, without the dot) and their languages
+const EXTENSIONS: &[(&str, &str)] = &[
+    ("rs", "Rust"),
+    ("go", "Go"),
+    ("ts", "TypeScript"),
+    ("tsx", "TypeScript"),
+    ("mts", "TypeScript"),
+    ("cts", "TypeScript"),
+    ("js", "JavaScript"),
+    ("jsx", "JavaScript"),
+    ("mjs", "JavaScript"),
+    ("cjs", "JavaScript"),
+    ("py", "Python"),
+    ("pyi", "Python"),
+    ("rb", "Ruby"),
+    ("java", "Java"),
+    ("kt", "Kotlin"),
+    ("kts", "Kotlin"),
+    ("scala", "Scala"),
+    ("swift", "Swift"),
+    ("c", "C"),
+    ("h", "C"),
+    ("cc", "C++"),
+    ("cpp", "C++"),
+    ("cxx", "C++"),
+    ("hpp", "C++"),
+    ("hh", "C++"),
+    ("cs", "C#"),
+    ("fs", "F#"),
+    ("php", "PHP"),
+    ("pl", "Perl"),
+    ("pm", "Perl"),
+    ("lua", "Lua"),
+    ("dart", "Dart"),
+    ("ex", "Elixir"),
+    ("exs", "Elixir"),
+    ("erl", "Erlang"),
+    ("hs", "Haskell"),
+    ("ml", "OCaml"),
+    ("clj", "Clojure"),
+    ("zig", "Zig"),
+    ("sh", "Shell"),
+    ("bash", "Shell"),
+    ("zsh", "Shell"),
+    ("fish", "Shell"),
+    ("ps1", "PowerShell"),
+    ("sql", "SQL"),
+    ("html", "HTML"),
+    ("htm", "HTML"),
+    ("css", "CSS"),
+    ("scss", "SCSS"),
+    ("sass", "SCSS"),
+    ("less", "Less"),
+    ("vue", "Vue"),
+    ("svelte", "Svelte"),
+    ("md", "Markdown"),
+    ("markdown", "Markdown"),
+    ("rst", "reStructuredText"),
+    ("json", "JSON"),
+    ("yaml", "YAML"),
+    ("yml", "YAML"),
+    ("toml", "TOML"),
+    ("xml", "XML"),
+    ("proto", "Protocol Buffers"),
+    ("graphql", "GraphQL"),
+    ("gql", "GraphQL"),
+    ("tf", "HCL"),
+    ("hcl", "HCL"),
+    ("nix", "Nix"),
+];
+
+/// Well-known file names without a meaningful extension
+const FILENAMES: &[(&str, &str)] = &[
+    ("Makefile", "Makefile"),
+    ("GNUmakefile", "Makefile"),
+    ("Dockerfile", "Dockerfile"),
+    ("Containerfile", "Dockerfile"),
+    ("CMakeLists.txt", "CMake"),
+    ("Rakefile", "Ruby"),
+    ("Gemfile", "Ruby"),
+    ("Jenkinsfile", "Groovy"),
+    ("Vagrantfile", "Ruby"),
+    ("BUILD", "Starlark"),
+    ("BUILD.bazel", "Starlark"),
+    ("WORKSPACE", "Starlark"),
+    ("go.mod", "Go Module"),
+    ("go.sum", "Go Module"),
+];
+
+/// Interpreters named on a shebang line
+const INTERPRETERS: &[(&str, &str)] = &[
+    ("python", "Python"),
+    ("node", "JavaScript"),
+    ("deno", "TypeScript"),
+    ("ruby", "Ruby"),
+    ("perl", "Perl"),
+    ("php", "PHP"),
+    ("bash", "Shell"),
+    ("sh", "Shell"),
+    ("zsh", "Shell"),
+    ("fish", "Shell"),
+    ("lua", "Lua"),
+];
+
+/// Detect a language from a file's name alone
+pub fn language_from_path(path: &str) -> Option<&'static str> {
+    let path = Path::new(path);
+    let file_name = path.file_name()?.to_string_lossy();
+
+    if let Some((_, language)) = FILENAMES.iter().find(|(name, _)| *name == file_name) {
+        return Some(language);
+    }
+    if file_name.starts_with("Dockerfile.") {
+        return Some("Dockerfile");
+    }
+
+    let extension = path.extension()?.to_string_lossy().to_ascii_lowercase();
+    EXTENSIONS
+        .iter()
+        .find(|(ext, _)| *ext == extension)
+        .map(|(_, language)| *language)
+}
+
+/// Detect a language from a `#!` line, e.g. `#!/usr/bin/env python3`
+pub fn language_from_shebang(first_line: &str) -> Option<&'static str> {
+    let command = first_line.strip_prefix("#!")?.trim();
+    let mut parts = command.split_whitespace();
+    let mut program = parts.next()?.rsplit('/').next()?;
+    if program == "env" {
+        // Skip env flags such as `-S`
+        program = parts.find(|part| !part.starts_with('-'))?;
+    }
+
+    // Strip version suffixes: python3.12 -> python
+    let program = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    INTERPRETERS
+        .iter()
+        .find(|(name, _)| *name == program)
+        .map(|(_, language)| *language)
+}
+
+/// Detects file languages, honoring `.gitattributes` `linguist-language` overrides
+pub struct LanguageDetector<'r> {
+    repo: &'r Repository,
+    cache: HashMap<String, String>,
+}
+
+impl<'r> LanguageDetector<'r> {
+    pub fn new(repo: &'r Repository) -> Self {
+        Self {
+            repo,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Language of a file; `blob` is only read for files that need a shebang check
+    pub fn detect(&mut self, path: &str, blob: Option<Oid>) -> String {
+        if let Some(language) = self.cache.get(path) {
+            return language.clone();
+        }
+
+        let language = self
+            .linguist_override(path)
+            .or_else(|| language_from_path(path).map(str::to_string))
+            .or_else(|| self.shebang_language(path, blob))
+            .unwrap_or_else(|| UNKNOWN_LANGUAGE.to_string());
+
+        self.cache.insert(path.to_string(), language.clone());
+        language
+    }
+
+    fn linguist_override(&self, path: &str) -> Option<String> {
+        // Attributes are read from the working tree, then the index
+        let value = self
+            .repo
+            .get_attr(Path::new(path), "linguist-language", AttrCheckFlags::FILE_THEN_INDEX)
+            .ok()?;
+        match AttrValue::from_string(value) {
+            AttrValue::String(language) => Some(language.to_string()),
+            _ => None,
+        }
+    }
+
+    fn shebang_language(&self, path: &str, blob: Option<Oid>) -> Option<String> {
+        // Only extensionless files (scripts) are worth opening
+        if Path::new(path).extension().is_some() {
+            return None;
+        }
+        let blob = self.repo.find_blob(blob?).ok()?;
+        let content = blob.content();
+        let first_line = content.split(|&b| b == b'\n').next()?;
+        language_from_shebang(&String::from_utf8_lossy(first_line)).map(str::to_string)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct LanguageStats {
+    pub language: String,
+    /// Files at HEAD
+    pub files: usize,
+    /// Lines of code at HEAD
+    pub lines: usize,
+    pub commits: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    pub contributors: Vec<String>,
+}
+
+impl LanguageStats {
+    /// Total lines changed (added + deleted) during the period
+    pub const fn churn(&self) -> usize {
+        self.additions + self.deletions
+    }
+}
+
+/// Churn per language during the period and lines of code per language at HEAD
+pub fn analyze_languages(repo: &Repository, days: i64) -> Result<Vec<LanguageStats>, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut detector = LanguageDetector::new(repo);
+    let mut language_map: HashMap<String, LanguageStats> = HashMap::new();
+
+    count_head_lines(repo, &mut detector, &mut language_map)?;
+
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        if !is_included(&commit, repo, days) {
+            continue;
+        }
+
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let author_name = author.name().unwrap_or("unknown").to_string();
+        let mut touched: HashSet<String> = HashSet::new();
+
+        for file in get_file_line_stats(&commit, repo)? {
+            let language = detector.detect(&file.path, Some(file.blob));
+            let entry = language_map.entry(language.clone()).or_insert_with(|| LanguageStats {
+                language: language.clone(),
+                ..Default::default()
+            });
+            entry.additions += file.additions;
+            entry.deletions += file.deletions;
+            if !entry.contributors.contains(&author_name) {
+                entry.contributors.push(author_name.clone());
+            }
+            touched.insert(language);
+        }
+
+        for language in touched {
+            if let Some(entry) = language_map.get_mut(&language) {
+                entry.commits += 1;
+            }
+        }
+    }
+
+    let mut languages: Vec<LanguageStats> = language_map.into_values().collect();
+    languages.sort_by_key(|l| Reverse((l.churn(), l.lines)));
+    Ok(languages)
+}
+
+/// Count files and lines per language in the HEAD tree
+fn count_head_lines(
+    repo: &Repository,
+    detector: &mut LanguageDetector,
+    language_map: &mut HashMap<String, LanguageStats>,
+) -> Result<(), git2::Error> {
+    // An unborn HEAD has no files yet
+    let Ok(head) = repo.head() else {
+        return Ok(());
+    };
+    let tree = head.peel_to_tree()?;
+
+    tree.walk(TreeWalkMode::PreOrder, |dir, entry| {
+        if entry.kind() != Some(ObjectType::Blob) {
+            return TreeWalkResult::Ok;
+        }
+        let Ok(blob) = repo.find_blob(entry.id()) else {
+            return TreeWalkResult::Ok;
+        };
+        if blob.is_binary() {
+            return TreeWalkResult::Ok;
+        }
+
+        let path = format!("{dir}{}", entry.name().unwrap_or_default());
+        let language = detector.detect(&path, Some(entry.id()));
+        let lines = count_lines(blob.content());
+        let stats = language_map.entry(language.clone()).or_insert_with(|| LanguageStats {
+            language,
+            ..Default::default()
+        });
+        stats.files += 1;
+        stats.lines += lines;
+        TreeWalkResult::Ok
+    })
+}
+
+/// Number of lines in a text blob, counting a trailing line without a newline
+#[allow(clippy::naive_bytecount)]
+pub fn count_lines(content: &[u8]) -> usize {
+    let newlines = content.iter().filter(|&&b| b == b'\n').count();
+    if content.last().is_some_and(|&b| b != b'\n') {
+        newlines + 1
+    } else {
+        newlines
+    }
+}
+
+/// Lines changed per language for each contributor, keyed like `analyze_contributors`
+pub fn analyze_contributor_languages(
+    repo: &Repository,
+    days: i64,
+) -> Result<HashMap<String, Vec<(String, usize)>>, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut detector = LanguageDetector::new(repo);
+    let mut mix_map: HashMap<String, HashMap<String, usize>> = HashMap::new();
+
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        if !is_included(&commit, repo, days) {
+            continue;
+        }
+
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let key = format!(
+            "{}|{}",
+            author.name().unwrap_or("unknown"),
+            author.email().unwrap_or("unknown")
+        );
+        let mix = mix_map.entry(key).or_default();
+        for file in get_file_line_stats(&commit, repo)? {
+            let language = detector.detect(&file.path, Some(file.blob));
+            *mix.entry(language).or_insert(0) += file.additions + file.deletions;
+        }
+    }
+
+    Ok(mix_map
+        .into_iter()
+        .map(|(key, mix)| {
+            let mut mix: Vec<(String, usize)> =
+                mix.into_iter().filter(|(_, lines)| *lines > 0).collect();
+            mix.sort_by_key(|(language, lines)| (Reverse(*lines), language.clone()));
+            (key, mix)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_from_path() {
+        assert_eq!(language_from_path("src/main.rs"), Some("Rust"));
+        assert_eq!(language_from_path("web/App.TSX"), Some("TypeScript"));
+        assert_eq!(language_from_path("build/Dockerfile.prod"), Some("Dockerfile"));
+        assert_eq!(language_from_path("Makefile"), Some("Makefile"));
+        assert_eq!(language_from_path("bin/run"), None);
+    }
+
+    #[test]
+    fn test_language_from_shebang() {
+        assert_eq!(language_from_shebang("#!/usr/bin/env python3"), Some("Python"));
+        assert_eq!(language_from_shebang("#!/usr/bin/env -S node --harmony"), Some("JavaScript"));
+        assert_eq!(language_from_shebang("#!/bin/bash -e"), Some("Shell"));
+        assert_eq!(language_from_shebang("echo hi"), None);
+    }
+
+    #[test]
+    fn test_count_lines() {
+        assert_eq!(count_lines(b""), 0);
+        assert_eq!(count_lines(b"a\nb\n"), 2);
+        assert_eq!(count_lines(b"a\nb"), 2);
+    }
+}
@@ -0,0 +1,206 @@
+use super::{
+    branches::resolve_main_branch, canonical_author, get_commit_stats, grouping::rollup,
+    is_within_days, median, percentile, time_to_datetime,
+};
+use chrono::{DateTime, Utc};
+use git2::{Repository, Sort};
+
+/// A branch merged into the main branch
+#[derive(Debug, Clone)]
+pub struct MergeInfo {
+    pub hash: String,
+    /// Pull/merge request number, when the merge message names one
+    pub pr: Option<u32>,
+    /// Source branch, when the merge message names one
+    pub branch: Option<String>,
+    /// Author of the branch's first commit
+    pub owner: String,
+    pub email: String,
+    pub merged_at: DateTime<Utc>,
+    /// Non-merge commits brought in by the merge
+    pub commits: usize,
+    pub authors: Vec<String>,
+    /// Lines added plus deleted by those commits
+    pub churn: usize,
+    /// Hours from the branch's first commit to the merge
+    pub lead_time: usize,
+}
+
+/// Lead-time distribution for one team or period
+#[derive(Debug, Default, Clone)]
+pub struct LeadTimeStats {
+    pub label: String,
+    /// Lead time of each merge, in hours
+    pub lead_times: Vec<usize>,
+    pub commits: usize,
+    pub churn: usize,
+}
+
+impl LeadTimeStats {
+    pub fn merges(&self) -> usize {
+        self.lead_times.len()
+    }
+
+    pub fn median(&self) -> usize {
+        median(&self.lead_times)
+    }
+
+    pub fn p75(&self) -> usize {
+        percentile(&self.lead_times, 75)
+    }
+
+    pub fn p90(&self) -> usize {
+        percentile(&self.lead_times, 90)
+    }
+
+    /// Collect merge lead times, commits and churn per team or period, ordered by key
+    pub fn group(merges: &[MergeInfo], key: impl Fn(&MergeInfo) -> String) -> Vec<Self> {
+        let new = |label| Self {
+            label,
+            ..Default::default()
+        };
+        rollup(merges, key, new, |stats, merge| {
+            stats.lead_times.push(merge.lead_time);
+            stats.commits += merge.commits;
+            stats.churn += merge.churn;
+        })
+    }
+}
+
+/// Merged branches, most recent first, and the main branch they were merged into
+#[derive(Debug, Default)]
+pub struct MergeReport {
+    pub main_branch: String,
+    pub merges: Vec<MergeInfo>,
+}
+
+/// Recover the PR number and source branch from a GitHub or GitLab merge message
+///
+/// Recognizes "Merge pull request #123 from owner/branch", "Merge branch 'branch' into
+/// 'main'" (with GitLab's "See merge request group/project!123" trailer) and
+/// "Merge remote-tracking branch 'origin/branch'".
+pub fn parse_merge_message(message: &str) -> (Option<u32>, Option<String>) {
+    let subject = message.lines().next().unwrap_or_default();
+
+    if let Some(rest) = subject.strip_prefix("Merge pull request #") {
+        let (number, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+        // "from owner/branch": drop the fork owner
+        let branch = rest
+            .strip_prefix("from ")
+            .map(|source| source.split_once('/').map_or(source, |(_, branch)| branch).to_string());
+        return (number.parse().ok(), branch);
+    }
+
+    let pr = message
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("See merge request "))
+        .and_then(|reference| reference.rsplit_once('!'))
+        .and_then(|(_, number)| number.trim().parse().ok());
+    let branch = ["Merge branch '", "Merge remote-tracking branch '"]
+        .iter()
+        .find_map(|prefix| subject.strip_prefix(prefix))
+        .and_then(|rest| rest.split_once('\''))
+        .map(|(branch, _)| branch.strip_prefix("origin/").unwrap_or(branch).to_string());
+    (pr, branch)
+}
+
+/// Lifetime, size and lead time of every branch merged into the main branch
+///
+/// Follows the first-parent history of the main branch; the commits a merge brings in are
+/// those reachable from its second parent but not its first.
+pub fn analyze_merges(
+    repo: &Repository,
+    days: i64,
+    main_branch: Option<&str>,
+) -> Result<MergeReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let (main_name, main_oid) = resolve_main_branch(repo, main_branch)?;
+    let mut merges = Vec::new();
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(main_oid)?;
+    revwalk.simplify_first_parent()?;
+
+    for oid in revwalk {
+        let merge = repo.find_commit(oid?)?;
+        if merge.parent_count() < 2 || !is_within_days(merge.time(), days) {
+            continue;
+        }
+
+        let mut branch_walk = repo.revwalk()?;
+        branch_walk.push(merge.parent_id(1)?)?;
+        branch_walk.hide(merge.parent_id(0)?)?;
+        branch_walk.set_sorting(Sort::TIME | Sort::REVERSE)?;
+
+        let mut first = None;
+        let mut commits = 0;
+        let mut churn = 0;
+        let mut authors: Vec<String> = Vec::new();
+        for branch_oid in branch_walk {
+            let commit = repo.find_commit(branch_oid?)?;
+            if commit.parent_count() > 1 {
+                continue;
+            }
+            let author = canonical_author(&commit, mailmap.as_ref());
+            let name = author.name().unwrap_or("unknown").to_string();
+            if !authors.contains(&name) {
+                authors.push(name.clone());
+            }
+            let (additions, deletions, _) = get_commit_stats(&commit, repo)?;
+            churn += additions + deletions;
+            commits += 1;
+            if first.is_none() {
+                let email = author.email().unwrap_or("unknown").to_string();
+                first = Some((name, email, time_to_datetime(author.when())));
+            }
+        }
+        // Nothing new, e.g. a merge of an already merged branch
+        let Some((owner, email, started_at)) = first else {
+            continue;
+        };
+
+        let merged_at = time_to_datetime(merge.time());
+        let (pr, branch) = parse_merge_message(merge.message().unwrap_or_default());
+        merges.push(MergeInfo {
+            hash: merge.id().to_string()[..7].to_string(),
+            pr,
+            branch,
+            owner,
+            email,
+            merged_at,
+            commits,
+            authors,
+            churn,
+            lead_time: usize::try_from((merged_at - started_at).num_hours()).unwrap_or(0),
+        });
+    }
+
+    Ok(MergeReport {
+        main_branch: main_name,
+        merges,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_merge_message() {
+        assert_eq!(
+            parse_merge_message("Merge pull request #123 from acme/feature/login\n\nAdd login"),
+            (Some(123), Some("feature/login".to_string()))
+        );
+        assert_eq!(
+            parse_merge_message(
+                "Merge branch 'feature/x' into 'main'\n\nSee merge request group/app!45"
+            ),
+            (Some(45), Some("feature/x".to_string()))
+        );
+        assert_eq!(
+            parse_merge_message("Merge remote-tracking branch 'origin/fix-1'"),
+            (None, Some("fix-1".to_string()))
+        );
+        assert_eq!(parse_merge_message("Add feature"), (None, None));
+    }
+}
@@ -1,13 +1,121 @@
+pub mod author;
+pub mod binaries;
+pub mod branches;
+pub mod changelog;
+pub mod commit_type;
 pub mod contributor;
+pub mod delivery;
+pub mod effort;
+pub mod experts;
 pub mod files;
+pub mod formatting;
+pub mod grouping;
+pub mod growth;
+pub mod issues;
+pub mod language;
+pub mod merges;
+pub mod onboarding;
+pub mod pull_requests;
+pub mod release;
+pub mod rework;
+pub mod roster;
+pub mod sizes;
+pub mod survival;
 pub mod time;
 
-pub use contributor::{analyze_contributors, ContributorStats};
-pub use files::{analyze_file_changes, FileStats};
-pub use time::{analyze_time_distribution, TimeStats};
+pub use author::{analyze_author, AuthorProfile};
+pub use binaries::{analyze_binaries, BinaryStats, BlobInfo, CommitBytes};
+pub use branches::{analyze_branches, BranchInfo};
+pub use changelog::{analyze_changelog, parse_range, Changelog, ChangelogEntry};
+pub use commit_type::{
+    analyze_commit_types, classify_message, CommitClass, CommitType, TypeBreakdown,
+};
+pub use contributor::{
+    analyze_contributor_groups, analyze_contributors, ContributorGroup, ContributorStats,
+};
+pub use delivery::{analyze_delivery, releases_per_week, DeliveryStats, Release};
+pub use effort::SessionOptions;
+pub use experts::{analyze_experts, ExpertStats};
+pub use files::{analyze_file_changes, sort_files, FileSort, FileStats};
+pub use formatting::{analyze_formatting, FormattingReport};
+pub use grouping::{GroupBy, PathGrouper};
+pub use growth::{analyze_growth, GrowthSample, SampleBy};
+pub use issues::{analyze_issues, ContributorIssues, IssueMatcher, IssueRef, IssueReport};
+pub use language::{
+    analyze_contributor_languages, analyze_languages, LanguageDetector, LanguageStats,
+};
+pub use merges::{analyze_merges, LeadTimeStats, MergeInfo};
+pub use onboarding::{analyze_onboarding, OnboardingReport, RampUp, RampWeek};
+pub use pull_requests::{analyze_pull_requests, PullRequestReport};
+pub use release::{analyze_release, ReleaseSummary};
+pub use rework::{analyze_rework, ReworkReport, ReworkStats};
+pub use roster::{analyze_roster, ActivityStatus, ActivityWindows, RosterEntry, RosterReport};
+pub use sizes::{analyze_commit_sizes, size_histogram, SizeReport, SIZE_BUCKETS};
+pub use survival::{analyze_survival, SurvivalStats, CHECKPOINTS};
+pub use time::{analyze_activity, analyze_time_distribution, Interval, PeriodActivity, TimeStats};
 
 use chrono::{DateTime, Duration, Utc};
-use git2::{Commit, DiffOptions, Repository, Time};
+use git2::{
+    BlameOptions, Commit, Delta, Diff, DiffOptions, Mailmap, Oid, Patch, Repository, Signature,
+    Time,
+};
+use std::{collections::HashMap, path::Path, sync::OnceLock};
+
+/// Settings that apply to every analysis, set once at startup
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnalysisOptions {
+    /// Leave out commits, merges included, changing more lines than this
+    pub max_commit_lines: Option<usize>,
+    /// Don't count whitespace, line-ending or blank-line changes as changed lines
+    pub ignore_whitespace: bool,
+    /// Leave out commits that only change formatting
+    pub exclude_formatting: bool,
+    /// Session grouping for effort estimates
+    pub session: SessionOptions,
+}
+
+static OPTIONS: OnceLock<AnalysisOptions> = OnceLock::new();
+
+/// Set the options for this run (only the first call has an effect)
+pub fn set_options(options: AnalysisOptions) {
+    let _ = OPTIONS.set(options);
+}
+
+fn options() -> AnalysisOptions {
+    #[cfg(test)]
+    if let Some(options) = tests::TEST_OPTIONS.with(std::cell::Cell::get) {
+        return options;
+    }
+    OPTIONS.get().copied().unwrap_or_default()
+}
+
+/// Line counts for a single file touched by a commit
+#[derive(Debug, Clone)]
+pub struct FileLineStats {
+    pub path: String,
+    /// Blob of the file after the commit (before it, for deletions)
+    pub blob: Oid,
+    pub status: Delta,
+    /// Binary files have no line counts
+    pub binary: bool,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+/// Median of unsorted values (0 for an empty slice)
+pub fn median(values: &[usize]) -> usize {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    sorted.get(sorted.len() / 2).copied().unwrap_or(0)
+}
+
+/// Nearest-rank percentile of unsorted values (0 for an empty slice)
+pub fn percentile(values: &[usize], percent: usize) -> usize {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let rank = (sorted.len() * percent + 99) / 100;
+    sorted.get(rank.saturating_sub(1)).copied().unwrap_or(0)
+}
 
 pub fn time_to_datetime(time: Time) -> DateTime<Utc> {
     use chrono::TimeZone;
@@ -24,10 +132,57 @@ pub fn is_within_days(commit_time: Time, days: i64) -> bool {
     commit_dt >= cutoff
 }
 
-pub fn get_commit_stats(
+/// Resolve a commit author through the repository's .mailmap, if any
+///
+/// Every analysis identifies people by this, so aliases are merged the same way everywhere.
+pub fn canonical_author<'a>(commit: &'a Commit, mailmap: Option<&Mailmap>) -> Signature<'a> {
+    mailmap
+        .and_then(|mailmap| commit.author_with_mailmap(mailmap).ok())
+        .unwrap_or_else(|| commit.author())
+}
+
+/// Whether a commit is in the analysis period and not dropped as an outlier or formatting-only
+pub fn is_included(commit: &Commit, repo: &Repository, days: i64) -> bool {
+    if !is_within_days(commit.time(), days) {
+        return false;
+    }
+    let options = options();
+    if options
+        .max_commit_lines
+        .is_some_and(|max_lines| is_outlier(commit, repo, max_lines))
+    {
+        return false;
+    }
+    !(options.exclude_formatting && is_formatting_only(commit, repo).unwrap_or(false))
+}
+
+/// Whether a commit changes more than `max_lines` lines
+///
+/// A merge is measured by its diff against the first parent, which repeats the merged
+/// branch, so merging an outlier makes an outlier too.
+fn is_outlier(commit: &Commit, repo: &Repository, max_lines: usize) -> bool {
+    get_commit_stats(commit, repo)
+        .is_ok_and(|(additions, deletions, _)| additions + deletions > max_lines)
+}
+
+/// Diff options for counting lines, optionally ignoring whitespace-only changes
+///
+/// Like `git diff -w --ignore-blank-lines`: all whitespace is ignored, so `a+b` to `a + b`
+/// is not a change either.
+fn diff_options(ignore_whitespace: bool) -> DiffOptions {
+    let mut diff_opts = DiffOptions::new();
+    if ignore_whitespace {
+        diff_opts.ignore_whitespace(true).ignore_blank_lines(true);
+    }
+    diff_opts
+}
+
+/// Diff a commit against its first parent (or the empty tree for root commits)
+fn diff_with_parent<'r>(
     commit: &Commit,
-    repo: &Repository,
-) -> Result<(usize, usize, usize), git2::Error> {
+    repo: &'r Repository,
+    diff_opts: &mut DiffOptions,
+) -> Result<Diff<'r>, git2::Error> {
     let a = if commit.parent_count() > 0 {
         let parent = commit.parent(0)?;
         Some(parent.tree()?)
@@ -36,9 +191,236 @@ pub fn get_commit_stats(
     };
     let b = commit.tree()?;
 
-    let mut diff_opts = DiffOptions::new();
-    let diff = repo.diff_tree_to_tree(a.as_ref(), Some(&b), Some(&mut diff_opts))?;
+    repo.diff_tree_to_tree(a.as_ref(), Some(&b), Some(diff_opts))
+}
+
+/// Diff a commit against its first parent, honoring `--ignore-whitespace`
+pub fn commit_diff<'r>(commit: &Commit, repo: &'r Repository) -> Result<Diff<'r>, git2::Error> {
+    diff_with_parent(commit, repo, &mut diff_options(options().ignore_whitespace))
+}
+
+/// Lines changed by a commit, as (all lines, lines ignoring whitespace-only changes)
+pub fn formatting_line_counts(
+    commit: &Commit,
+    repo: &Repository,
+) -> Result<(usize, usize), git2::Error> {
+    let count = |ignore_whitespace| -> Result<usize, git2::Error> {
+        let stats =
+            diff_with_parent(commit, repo, &mut diff_options(ignore_whitespace))?.stats()?;
+        Ok(stats.insertions() + stats.deletions())
+    };
+    Ok((count(false)?, count(true)?))
+}
+
+/// Whether a non-merge commit changes lines, but only their whitespace, line endings or blank lines
+pub fn is_formatting_only(commit: &Commit, repo: &Repository) -> Result<bool, git2::Error> {
+    if commit.parent_count() > 1 {
+        return Ok(false);
+    }
+    let (lines, substantive) = formatting_line_counts(commit, repo)?;
+    Ok(lines > 0 && substantive == 0)
+}
 
+pub fn get_commit_stats(
+    commit: &Commit,
+    repo: &Repository,
+) -> Result<(usize, usize, usize), git2::Error> {
+    let diff = commit_diff(commit, repo)?;
     let stats = diff.stats()?;
     Ok((stats.insertions(), stats.deletions(), stats.files_changed()))
 }
+
+/// Per-file additions/deletions for a commit
+pub fn get_file_line_stats(
+    commit: &Commit,
+    repo: &Repository,
+) -> Result<Vec<FileLineStats>, git2::Error> {
+    let diff = commit_diff(commit, repo)?;
+    let mut files = Vec::with_capacity(diff.deltas().len());
+
+    for (idx, delta) in diff.deltas().enumerate() {
+        let Some(file_path) = delta.new_file().path().or_else(|| delta.old_file().path()) else {
+            continue;
+        };
+        let (additions, deletions, binary) = match Patch::from_diff(&diff, idx)? {
+            Some(patch) => {
+                let (_, additions, deletions) = patch.line_stats()?;
+                (additions, deletions, patch.delta().flags().is_binary())
+            }
+            None => (0, 0, false),
+        };
+        let blob = if delta.status() == Delta::Deleted {
+            delta.old_file().id()
+        } else {
+            delta.new_file().id()
+        };
+        files.push(FileLineStats {
+            path: file_path.to_string_lossy().to_string(),
+            blob,
+            status: delta.status(),
+            binary,
+            additions,
+            deletions,
+        });
+    }
+
+    Ok(files)
+}
+
+/// Lines a commit deleted or modified in one file that were written by one earlier commit
+#[derive(Debug, Clone)]
+pub struct DeletedLines {
+    pub path: String,
+    /// Commit that wrote the lines
+    pub origin: Oid,
+    pub lines: usize,
+}
+
+/// Which commits wrote the lines a commit deleted or modified
+///
+/// Deleted line numbers come from a zero-context diff against the first parent; blame on the
+/// parent tells which commit wrote each of those lines.
+pub fn deleted_line_origins(
+    commit: &Commit,
+    repo: &Repository,
+) -> Result<Vec<DeletedLines>, git2::Error> {
+    let Ok(parent) = commit.parent(0) else {
+        return Ok(Vec::new());
+    };
+
+    let mut diff_opts = diff_options(options().ignore_whitespace);
+    diff_opts.context_lines(0);
+    let diff =
+        repo.diff_tree_to_tree(Some(&parent.tree()?), Some(&commit.tree()?), Some(&mut diff_opts))?;
+
+    let mut origins = Vec::new();
+    for idx in 0..diff.deltas().len() {
+        let Some(patch) = Patch::from_diff(&diff, idx)? else {
+            continue;
+        };
+        let Some(old_path) = patch.delta().old_file().path().map(Path::to_path_buf) else {
+            continue;
+        };
+
+        let deleted: Vec<(usize, usize)> = (0..patch.num_hunks())
+            .filter_map(|hunk_idx| patch.hunk(hunk_idx).ok())
+            .map(|(hunk, _)| (hunk.old_start() as usize, hunk.old_lines() as usize))
+            .filter(|(_, lines)| *lines > 0)
+            .collect();
+        let (Some(first), Some(last)) =
+            (deleted.first(), deleted.last().map(|(start, lines)| start + lines - 1))
+        else {
+            continue;
+        };
+
+        let mut blame_opts = BlameOptions::new();
+        blame_opts.newest_commit(parent.id()).min_line(first.0).max_line(last);
+        let Ok(blame) = repo.blame_file(&old_path, Some(&mut blame_opts)) else {
+            continue;
+        };
+
+        let mut by_origin: HashMap<Oid, usize> = HashMap::new();
+        for line in deleted.iter().flat_map(|(start, lines)| *start..start + lines) {
+            if let Some(hunk) = blame.get_line(line) {
+                *by_origin.entry(hunk.final_commit_id()).or_insert(0) += 1;
+            }
+        }
+        let file_path = old_path.to_string_lossy().to_string();
+        origins.extend(by_origin.into_iter().map(|(origin, lines)| DeletedLines {
+            path: file_path.clone(),
+            origin,
+            lines,
+        }));
+    }
+    Ok(origins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use git2::{Index, IndexEntry, IndexTime, Signature, Time};
+    use std::{cell::Cell, env, error::Error, fs, path::PathBuf};
+
+    thread_local! {
+        /// Options for the current test; `OPTIONS` is shared by every test in the process
+        pub static TEST_OPTIONS: Cell<Option<AnalysisOptions>> = const { Cell::new(None) };
+    }
+
+    /// An empty bare repository in a fresh temporary directory
+    pub fn temp_repo(name: &str) -> Result<(PathBuf, Repository), git2::Error> {
+        let dir = env::temp_dir().join(format!("gtct-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let repo = Repository::init_bare(&dir)?;
+        Ok((dir, repo))
+    }
+
+    /// Commit `files` (path, content) at `time` on top of `parents` without a work tree
+    pub fn commit(
+        repo: &Repository,
+        time: i64,
+        parents: &[Oid],
+        files: &[(&str, &[u8])],
+    ) -> Result<Oid, git2::Error> {
+        commit_as(repo, ("Alice", "alice@example.com"), "commit", time, parents, files)
+    }
+
+    /// Like [`commit`], with an author (name, email) and message; paths may contain directories
+    pub fn commit_as(
+        repo: &Repository,
+        (name, email): (&str, &str),
+        message: &str,
+        time: i64,
+        parents: &[Oid],
+        files: &[(&str, &[u8])],
+    ) -> Result<Oid, git2::Error> {
+        let mut index = Index::new()?;
+        for (path, content) in files {
+            let entry = IndexEntry {
+                ctime: IndexTime::new(0, 0),
+                mtime: IndexTime::new(0, 0),
+                dev: 0,
+                ino: 0,
+                mode: 0o100_644,
+                uid: 0,
+                gid: 0,
+                file_size: 0,
+                id: repo.blob(content)?,
+                flags: 0,
+                flags_extended: 0,
+                path: path.as_bytes().to_vec(),
+            };
+            index.add(&entry)?;
+        }
+        let tree = repo.find_tree(index.write_tree_to(repo)?)?;
+        let signature = Signature::new(name, email, &Time::new(time, 0))?;
+        let parents = parents
+            .iter()
+            .map(|oid| repo.find_commit(*oid))
+            .collect::<Result<Vec<_>, _>>()?;
+        let parents: Vec<&Commit> = parents.iter().collect();
+        repo.commit(None, &signature, &signature, message, &tree, &parents)
+    }
+
+    #[test]
+    fn test_merged_outlier_is_outlier() -> Result<(), Box<dyn Error>> {
+        let (dir, repo) = temp_repo("outlier")?;
+        let lines = |count: usize| "line\n".repeat(count).into_bytes();
+        let (small, edited, vendored) = (lines(10), lines(12), lines(5000));
+
+        let base = commit(&repo, 0, &[], &[("a.txt", &small)])?;
+        let main = commit(&repo, 0, &[base], &[("a.txt", &edited)])?;
+        let vendor = commit(&repo, 0, &[base], &[("a.txt", &small), ("vendor.txt", &vendored)])?;
+        let merged: [(&str, &[u8]); 2] = [("a.txt", &edited), ("vendor.txt", &vendored)];
+        let merge = commit(&repo, 0, &[main, vendor], &merged)?;
+        // Merging main back in brings nothing new along the first parent
+        let small_merge = commit(&repo, 0, &[merge, main], &merged)?;
+
+        let outliers = [main, vendor, merge, small_merge]
+            .map(|oid| repo.find_commit(oid).is_ok_and(|c| is_outlier(&c, &repo, 1000)));
+        assert_eq!(outliers, [false, true, true, false]);
+
+        drop(repo);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use super::{
+    canonical_author, get_file_line_stats, is_included, median, time_to_datetime, GroupBy,
+    PathGrouper,
+};
+use chrono::{DateTime, Utc};
+use git2::{Repository, Sort};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap, HashSet},
+};
+
+/// Activity in one week since a contributor's first commit
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RampWeek {
+    pub commits: usize,
+    /// Lines added plus deleted
+    pub churn: usize,
+    /// Distinct files touched since the first commit, up to and including this week
+    pub breadth: usize,
+}
+
+/// How one contributor ramped up after their first commit
+#[derive(Debug, Clone)]
+pub struct RampUp {
+    pub name: String,
+    pub email: String,
+    pub first_commit: DateTime<Utc>,
+    /// One entry per week that has elapsed, up to the ramp-up window
+    pub weeks: Vec<RampWeek>,
+    /// Days from the first commit to the first change in each top-level directory
+    pub directories: BTreeMap<String, usize>,
+}
+
+impl RampUp {
+    pub fn commits(&self) -> usize {
+        self.weeks.iter().map(|w| w.commits).sum()
+    }
+
+    pub fn churn(&self) -> usize {
+        self.weeks.iter().map(|w| w.churn).sum()
+    }
+
+    pub fn breadth(&self) -> usize {
+        self.weeks.last().map_or(0, |w| w.breadth)
+    }
+}
+
+/// New joiners and the team's historical ramp-up for comparison
+#[derive(Debug, Default)]
+pub struct OnboardingReport {
+    /// Contributors whose first commit falls in the analyzed period, newest first
+    pub joiners: Vec<RampUp>,
+    /// Median of each week across everyone who has been around that long
+    pub baseline: Vec<RampWeek>,
+    /// Median days to the first change in each top-level directory, across everyone
+    pub directories: BTreeMap<String, usize>,
+}
+
+/// Per-contributor state while walking history oldest first
+struct Tracker {
+    ramp: RampUp,
+    files: HashSet<String>,
+}
+
+/// Ramp-up curves for contributors who joined in the last `days` days
+///
+/// First commits come from the whole history, so `days` only selects who counts as new. Each
+/// curve covers the first `weeks` weeks after the first commit.
+pub fn analyze_onboarding(
+    repo: &Repository,
+    days: i64,
+    weeks: usize,
+) -> Result<OnboardingReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let grouper = PathGrouper::new(repo, GroupBy::Dir(1))?;
+    let now = Utc::now();
+    let mut trackers: HashMap<String, Tracker> = HashMap::new();
+
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+    revwalk.set_sorting(Sort::TIME | Sort::REVERSE)?;
+
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.parent_count() > 1 || !is_included(&commit, repo, 0) {
+            continue;
+        }
+
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let name = author.name().unwrap_or("unknown").to_string();
+        let email = author.email().unwrap_or("unknown").to_string();
+        let committed_at = time_to_datetime(commit.time());
+        let tracker = trackers.entry(format!("{name}|{email}")).or_insert_with(|| {
+            let elapsed = usize::try_from((now - committed_at).num_weeks()).unwrap_or(0) + 1;
+            Tracker {
+                ramp: RampUp {
+                    name,
+                    email,
+                    first_commit: committed_at,
+                    weeks: vec![RampWeek::default(); elapsed.min(weeks)],
+                    directories: BTreeMap::new(),
+                },
+                files: HashSet::new(),
+            }
+        });
+
+        // Clock skew can put a commit before the first one
+        let since_first =
+            usize::try_from((committed_at - tracker.ramp.first_commit).num_days()).unwrap_or(0);
+        let files = get_file_line_stats(&commit, repo)?;
+        for file in &files {
+            tracker.ramp.directories.entry(grouper.key(&file.path)).or_insert(since_first);
+        }
+
+        let week = since_first / 7;
+        if week < tracker.ramp.weeks.len() {
+            tracker.files.extend(files.iter().map(|f| f.path.clone()));
+            let entry = &mut tracker.ramp.weeks[week];
+            entry.commits += 1;
+            entry.churn += files.iter().map(|f| f.additions + f.deletions).sum::<usize>();
+            entry.breadth = tracker.files.len();
+        }
+    }
+
+    let mut everyone: Vec<RampUp> = trackers
+        .into_values()
+        .map(|mut tracker| {
+            // Quiet weeks keep the breadth reached so far
+            let mut breadth = 0;
+            for week in &mut tracker.ramp.weeks {
+                breadth = breadth.max(week.breadth);
+                week.breadth = breadth;
+            }
+            tracker.ramp
+        })
+        .collect();
+
+    let baseline = (0..weeks)
+        .map(|week| {
+            let values: Vec<RampWeek> =
+                everyone.iter().filter_map(|ramp| ramp.weeks.get(week).copied()).collect();
+            RampWeek {
+                commits: median(&values.iter().map(|w| w.commits).collect::<Vec<_>>()),
+                churn: median(&values.iter().map(|w| w.churn).collect::<Vec<_>>()),
+                breadth: median(&values.iter().map(|w| w.breadth).collect::<Vec<_>>()),
+            }
+        })
+        .collect();
+
+    let mut by_directory: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for ramp in &everyone {
+        for (dir, days) in &ramp.directories {
+            by_directory.entry(dir.clone()).or_default().push(*days);
+        }
+    }
+    let directories = by_directory.into_iter().map(|(dir, days)| (dir, median(&days))).collect();
+
+    everyone.retain(|ramp| days == 0 || (now - ramp.first_commit).num_days() <= days);
+    everyone.sort_by_key(|ramp| Reverse(ramp.first_commit));
+    Ok(OnboardingReport {
+        joiners: everyone,
+        baseline,
+        directories,
+    })
+}
@@ -0,0 +1,248 @@
+use super::{
+    canonical_author, get_commit_stats, is_included, median, merges::parse_merge_message,
+    percentile, time_to_datetime,
+};
+use chrono::{DateTime, Utc};
+use git2::{Commit, Mailmap, Repository, Signature, Sort, Time};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+/// One pull request, delivered as a squash commit or a merge commit
+#[derive(Debug, Clone)]
+pub struct PullRequest {
+    pub number: u32,
+    pub hash: String,
+    pub title: String,
+    pub author: String,
+    pub email: String,
+    /// `Co-authored-by` trailers, as (name, email)
+    pub co_authors: Vec<(String, String)>,
+    pub merged_at: DateTime<Utc>,
+    /// Lines added plus deleted
+    pub lines: usize,
+    pub files: usize,
+    pub squashed: bool,
+}
+
+/// Pull requests authored and co-authored by one contributor
+#[derive(Debug, Default, Clone)]
+pub struct PullRequestStats {
+    pub name: String,
+    pub email: String,
+    pub authored: usize,
+    pub co_authored: usize,
+    /// Size of each authored pull request, in lines changed
+    pub lines: Vec<usize>,
+}
+
+impl PullRequestStats {
+    pub fn median_lines(&self) -> usize {
+        median(&self.lines)
+    }
+
+    pub fn p90_lines(&self) -> usize {
+        percentile(&self.lines, 90)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PullRequestReport {
+    /// Most recent first
+    pub pull_requests: Vec<PullRequest>,
+    pub contributors: Vec<PullRequestStats>,
+}
+
+/// PR number from a squash-merge subject ending in `(#1234)`
+pub fn squash_pr_number(subject: &str) -> Option<u32> {
+    let (_, number) = subject.trim_end().strip_suffix(')')?.rsplit_once("(#")?;
+    number.parse().ok()
+}
+
+/// `Co-authored-by: Name <email>` trailers, as (name, email)
+pub fn co_authors(message: &str) -> Vec<(String, String)> {
+    message
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once(':')?;
+            if !key.eq_ignore_ascii_case("co-authored-by") {
+                return None;
+            }
+            let (name, email) = value.trim().split_once('<')?;
+            let email = email.strip_suffix('>')?;
+            Some((name.trim().to_string(), email.trim().to_string()))
+        })
+        .collect()
+}
+
+/// PR number of a commit: `(#N)` on a squash commit, or a GitHub/GitLab merge message
+pub fn pr_number(commit: &Commit) -> Option<u32> {
+    if commit.parent_count() > 1 {
+        return parse_merge_message(commit.message().unwrap_or_default()).0;
+    }
+    squash_pr_number(commit.summary().unwrap_or_default())
+}
+
+/// Who a PR is credited to, as (name, email): the squash commit's author, or for a merge
+/// commit the author of the merged branch's tip rather than whoever pressed the button
+pub fn pr_author(commit: &Commit, mailmap: Option<&Mailmap>) -> (String, String) {
+    let source = commit.parent(1).unwrap_or_else(|_| commit.clone());
+    let author = canonical_author(&source, mailmap);
+    (
+        author.name().unwrap_or("unknown").to_string(),
+        author.email().unwrap_or("unknown").to_string(),
+    )
+}
+
+/// Treat every squash or merge commit that names a PR as one unit of delivery
+///
+/// Sizes come from the diff against the first parent, so a merged branch counts as a whole.
+/// A PR cherry-picked or backported to another branch counts once, at its earliest merge.
+pub fn analyze_pull_requests(
+    repo: &Repository,
+    days: i64,
+) -> Result<PullRequestReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut pull_requests = Vec::new();
+    let mut stats_map: HashMap<String, PullRequestStats> = HashMap::new();
+    let mut seen: HashSet<u32> = HashSet::new();
+
+    let mut revwalk = repo.revwalk()?;
+    // Oldest first, so the first commit naming a PR is its original merge
+    revwalk.set_sorting(Sort::TIME | Sort::REVERSE)?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        if !is_included(&commit, repo, days) {
+            continue;
+        }
+        let Some(number) = pr_number(&commit).filter(|number| seen.insert(*number)) else {
+            continue;
+        };
+
+        let (name, email) = pr_author(&commit, mailmap.as_ref());
+        let message = commit.message().unwrap_or_default();
+        let (additions, deletions, files) = get_commit_stats(&commit, repo)?;
+        let lines = additions + deletions;
+
+        // Trailers go through .mailmap too, so a co-author matches their own commits
+        let co_authors: Vec<(String, String)> = co_authors(message)
+            .into_iter()
+            .map(|(name, email)| {
+                let resolved = mailmap.as_ref().and_then(|mailmap| {
+                    let signature = Signature::new(&name, &email, &Time::new(0, 0)).ok()?;
+                    mailmap.resolve_signature(&signature).ok()
+                });
+                match resolved {
+                    Some(signature) => (
+                        signature.name().unwrap_or(&name).to_string(),
+                        signature.email().unwrap_or(&email).to_string(),
+                    ),
+                    None => (name, email),
+                }
+            })
+            .collect();
+        for (co_name, co_email) in &co_authors {
+            stats_map
+                .entry(format!("{co_name}|{co_email}"))
+                .or_insert_with(|| PullRequestStats {
+                    name: co_name.clone(),
+                    email: co_email.clone(),
+                    ..Default::default()
+                })
+                .co_authored += 1;
+        }
+        let entry =
+            stats_map.entry(format!("{name}|{email}")).or_insert_with(|| PullRequestStats {
+                name: name.clone(),
+                email: email.clone(),
+                ..Default::default()
+            });
+        entry.authored += 1;
+        entry.lines.push(lines);
+
+        pull_requests.push(PullRequest {
+            number,
+            hash: oid.to_string()[..7].to_string(),
+            title: commit.summary().unwrap_or_default().to_string(),
+            author: name,
+            email,
+            co_authors,
+            merged_at: time_to_datetime(commit.time()),
+            lines,
+            files,
+            squashed: commit.parent_count() < 2,
+        });
+    }
+
+    pull_requests.sort_by_key(|pr| Reverse(pr.merged_at));
+    let mut contributors: Vec<PullRequestStats> = stats_map.into_values().collect();
+    contributors.sort_by_key(|s| Reverse((s.authored, s.co_authored)));
+    Ok(PullRequestReport {
+        pull_requests,
+        contributors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::tests::{commit_as, temp_repo};
+    use std::{error::Error, fs};
+
+    #[test]
+    fn test_squash_pr_number() {
+        assert_eq!(squash_pr_number("feat: add login (#1234)"), Some(1234));
+        assert_eq!(squash_pr_number("fix: handle (#12) in titles"), None);
+        assert_eq!(squash_pr_number("chore: bump deps"), None);
+    }
+
+    #[test]
+    fn test_co_authors() {
+        let message = "feat: pair on x (#5)\n\n\
+                       Co-authored-by: Carol <carol@example.com>\n\
+                       co-authored-by: Dave Smith <dave@example.com>\n";
+        assert_eq!(
+            co_authors(message),
+            vec![
+                ("Carol".to_string(), "carol@example.com".to_string()),
+                ("Dave Smith".to_string(), "dave@example.com".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_backported_pr_counts_once() -> Result<(), Box<dyn Error>> {
+        let (dir, repo) = temp_repo("pr-backport")?;
+        let alice = ("Alice", "alice@example.com");
+        let v1: &[u8] = b"one\n";
+        let v2: &[u8] = b"one\ntwo\n";
+        let v3: &[u8] = b"one\ntwo\nthree\n";
+
+        let base = commit_as(&repo, alice, "chore: init", 1_000, &[], &[("a.txt", v1)])?;
+        let fix = commit_as(&repo, alice, "fix: two (#7)", 2_000, &[base], &[("a.txt", v2)])?;
+        let feat = commit_as(&repo, alice, "feat: three (#8)", 3_000, &[fix], &[("a.txt", v3)])?;
+        // The same squash commit cherry-picked onto a release branch later
+        let backport = commit_as(&repo, alice, "fix: two (#7)", 4_000, &[base], &[("a.txt", v2)])?;
+        repo.reference("refs/heads/main", feat, true, "test")?;
+        repo.reference("refs/heads/release-1.x", backport, true, "test")?;
+
+        let report = analyze_pull_requests(&repo, 0)?;
+        let numbers: Vec<(u32, &str)> =
+            report.pull_requests.iter().map(|pr| (pr.number, pr.hash.as_str())).collect();
+        let fix = fix.to_string();
+        let feat = feat.to_string();
+        assert_eq!(numbers, [(8, &feat[..7]), (7, &fix[..7])]);
+        assert_eq!(report.contributors.len(), 1);
+        assert_eq!(report.contributors[0].authored, 2);
+
+        drop(repo);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use super::{
+    canonical_author, changelog::authors_reachable_from, classify_message, get_file_line_stats,
+    sort_files, time_to_datetime, ContributorStats, FileSort, FileStats, GroupBy, PathGrouper,
+    TypeBreakdown,
+};
+use chrono::{DateTime, Utc};
+use git2::{Commit, Oid, Repository};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+/// Who contributed what to one release
+#[derive(Debug)]
+pub struct ReleaseSummary {
+    pub tag: String,
+    /// Previous release; `None` when the release has no earlier tag
+    pub from: Option<String>,
+    pub date: DateTime<Utc>,
+    pub contributors: Vec<ContributorStats>,
+    /// `name|email` keys of contributors with no commit before `from`
+    pub first_time: HashSet<String>,
+    pub files: Vec<FileStats>,
+    /// Churn per top-level directory
+    pub directories: Vec<FileStats>,
+    pub types: TypeBreakdown,
+}
+
+impl ReleaseSummary {
+    /// Lines added plus deleted across the release
+    pub fn churn(&self) -> usize {
+        self.contributors.iter().map(|s| s.additions + s.deletions).sum()
+    }
+
+    pub fn commits(&self) -> usize {
+        self.contributors.iter().map(|s| s.commits).sum()
+    }
+}
+
+/// The closest tag matching `pattern` that is a strict ancestor of `commit`
+pub fn previous_tag(
+    repo: &Repository,
+    commit: &Commit,
+    pattern: Option<&str>,
+) -> Result<Option<(String, Oid)>, git2::Error> {
+    let mut best: Option<(String, Oid, i64)> = None;
+    for name in repo.tag_names(pattern)?.iter().flatten() {
+        // Tags on a tree or blob are not releases
+        let Ok(candidate) = repo.revparse_single(&format!("refs/tags/{name}"))?.peel_to_commit()
+        else {
+            continue;
+        };
+        if candidate.id() == commit.id()
+            || !repo.graph_descendant_of(commit.id(), candidate.id())?
+        {
+            continue;
+        }
+        let time = candidate.time().seconds();
+        if best.as_ref().map_or(true, |(_, _, best_time)| time > *best_time) {
+            best = Some((name.to_string(), candidate.id(), time));
+        }
+    }
+    Ok(best.map(|(name, oid, _)| (name, oid)))
+}
+
+fn record_path(
+    map: &mut HashMap<String, FileStats>,
+    key: String,
+    lines: (usize, usize),
+    author: &str,
+) {
+    let entry = map.entry(key.clone()).or_insert_with(|| FileStats {
+        path: key,
+        ..Default::default()
+    });
+    entry.changes += 1;
+    entry.additions += lines.0;
+    entry.deletions += lines.1;
+    if !entry.contributors.iter().any(|c| c == author) {
+        entry.contributors.push(author.to_string());
+    }
+}
+
+/// Summarize the commits in `from..tag` (merge commits are left out)
+///
+/// Without `from`, the previous release is the closest earlier tag matching `pattern`.
+pub fn analyze_release(
+    repo: &Repository,
+    tag: &str,
+    from: Option<&str>,
+    pattern: Option<&str>,
+) -> Result<ReleaseSummary, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let tag_commit = repo.revparse_single(tag)?.peel_to_commit()?;
+    let from = match from {
+        Some(from) => Some((from.to_string(), repo.revparse_single(from)?.peel_to_commit()?.id())),
+        None => previous_tag(repo, &tag_commit, pattern)?,
+    };
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(tag_commit.id())?;
+    let known_authors = match &from {
+        Some((_, oid)) => {
+            revwalk.hide(*oid)?;
+            authors_reachable_from(repo, *oid)?
+        }
+        None => HashSet::new(),
+    };
+
+    let grouper = PathGrouper::new(repo, GroupBy::Dir(1))?;
+    let mut first_time = HashSet::new();
+    let mut types = TypeBreakdown::default();
+    let mut stats_map: HashMap<String, ContributorStats> = HashMap::new();
+    let mut file_map: HashMap<String, FileStats> = HashMap::new();
+    let mut dir_map: HashMap<String, FileStats> = HashMap::new();
+
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.parent_count() > 1 {
+            continue;
+        }
+
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let name = author.name().unwrap_or("unknown").to_string();
+        let email = author.email().unwrap_or("unknown").to_string();
+        let key = format!("{name}|{email}");
+        if from.is_some() && !known_authors.contains(&email.to_lowercase()) {
+            first_time.insert(key.clone());
+        }
+        types.record(&classify_message(commit.message().unwrap_or_default()));
+
+        let file_stats = get_file_line_stats(&commit, repo)?;
+        let entry = stats_map.entry(key).or_insert_with(|| ContributorStats {
+            name: name.clone(),
+            email,
+            ..Default::default()
+        });
+        entry.commits += 1;
+        entry.files_changed += file_stats.len();
+        entry.record_commit_time(time_to_datetime(commit.time()));
+
+        // A commit touching several files in one directory counts as a single change
+        let mut touched: HashMap<String, (usize, usize)> = HashMap::new();
+        for file in &file_stats {
+            entry.additions += file.additions;
+            entry.deletions += file.deletions;
+            record_path(&mut file_map, file.path.clone(), (file.additions, file.deletions), &name);
+            let lines = touched.entry(grouper.key(&file.path)).or_default();
+            lines.0 += file.additions;
+            lines.1 += file.deletions;
+        }
+        for (dir, lines) in touched {
+            record_path(&mut dir_map, dir, lines, &name);
+        }
+    }
+
+    let mut contributors: Vec<ContributorStats> = stats_map.into_values().collect();
+    contributors.sort_by_key(|s| (Reverse(s.commits), Reverse(s.additions + s.deletions)));
+    let mut files: Vec<FileStats> = file_map.into_values().collect();
+    sort_files(&mut files, FileSort::Churn);
+    let mut directories: Vec<FileStats> = dir_map.into_values().collect();
+    sort_files(&mut directories, FileSort::Churn);
+
+    Ok(ReleaseSummary {
+        tag: tag.to_string(),
+        from: from.map(|(name, _)| name),
+        date: time_to_datetime(tag_commit.time()),
+        contributors,
+        first_time,
+        files,
+        directories,
+        types,
+    })
+}
@@ -0,0 +1,271 @@
+use super::{
+    canonical_author, deleted_line_origins, grouping::rollup, is_included, time_to_datetime,
+    Interval, PathGrouper,
+};
+use chrono::{DateTime, Utc};
+use git2::{Commit, Oid, Repository};
+use std::{cmp::Reverse, collections::HashMap};
+
+/// Rework counts for one contributor, directory or period
+#[derive(Debug, Default, Clone)]
+pub struct ReworkStats {
+    pub label: String,
+    pub email: String,
+    pub commits: usize,
+    /// `fixup!` / `squash!` / `amend!` commits
+    pub fixups: usize,
+    /// Revert commits
+    pub reverts: usize,
+    /// Commits that were later reverted
+    pub reverted: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Deleted or modified lines that had been written within the rework window
+    pub rework: usize,
+}
+
+impl ReworkStats {
+    /// Share of deleted/modified lines that were rework, in percent
+    pub fn rework_rate(&self) -> usize {
+        (self.rework * 100).checked_div(self.deletions).unwrap_or(0)
+    }
+
+    fn absorb(&mut self, other: &Self) {
+        self.commits += other.commits;
+        self.fixups += other.fixups;
+        self.reverts += other.reverts;
+        self.reverted += other.reverted;
+        self.additions += other.additions;
+        self.deletions += other.deletions;
+        self.rework += other.rework;
+    }
+
+    /// Add up rework per directory or period across contributors, ordered by key
+    pub fn rollup(stats: &[Self], key: impl Fn(&Self) -> String) -> Vec<Self> {
+        let new = |label| Self {
+            label,
+            ..Default::default()
+        };
+        rollup(stats, key, new, Self::absorb)
+    }
+}
+
+/// A revert commit matched to the commit it reverts
+#[derive(Debug, Clone)]
+pub struct RevertInfo {
+    pub hash: String,
+    pub reverted_hash: String,
+    pub summary: String,
+    pub author: String,
+    pub email: String,
+    /// Author of the reverted commit
+    pub reverted_author: String,
+    /// Days between the reverted commit and its revert
+    pub days_to_revert: i64,
+}
+
+/// Rework per contributor, per directory and over time
+#[derive(Debug, Default)]
+pub struct ReworkReport {
+    pub contributors: Vec<ReworkStats>,
+    /// One entry per directory and contributor, labeled with the directory
+    pub directories: Vec<ReworkStats>,
+    /// One entry per period and contributor, labeled with the period
+    pub timeline: Vec<ReworkStats>,
+    pub reverts: Vec<RevertInfo>,
+}
+
+/// Whether a subject marks a commit to be folded into another by `rebase --autosquash`
+pub fn is_fixup(subject: &str) -> bool {
+    ["fixup!", "squash!", "amend!"].iter().any(|prefix| subject.starts_with(prefix))
+}
+
+/// The hash a `git revert` message points at ("This reverts commit <sha>."), which may
+/// be abbreviated
+pub fn reverted_hash(message: &str) -> Option<&str> {
+    let (_, rest) = message.split_once("This reverts commit ")?;
+    let len = rest.find(|c: char| !c.is_ascii_hexdigit()).unwrap_or(rest.len());
+    Some(&rest[..len]).filter(|hash| (7..=40).contains(&hash.len()))
+}
+
+/// Lines this commit deleted or modified that were written less than `window_days` before it
+fn rework_lines(
+    commit: &Commit,
+    repo: &Repository,
+    window_days: i64,
+    commit_times: &mut HashMap<Oid, DateTime<Utc>>,
+) -> Result<Vec<(String, usize)>, git2::Error> {
+    let committed_at = time_to_datetime(commit.time());
+    let mut files: Vec<(String, usize)> = Vec::new();
+
+    for deleted in deleted_line_origins(commit, repo)? {
+        let written_at = if let Some(time) = commit_times.get(&deleted.origin) {
+            *time
+        } else {
+            let time = time_to_datetime(repo.find_commit(deleted.origin)?.time());
+            commit_times.insert(deleted.origin, time);
+            time
+        };
+        if (committed_at - written_at).num_days() >= window_days {
+            continue;
+        }
+        match files.iter_mut().find(|(path, _)| *path == deleted.path) {
+            Some((_, lines)) => *lines += deleted.lines,
+            None => files.push((deleted.path, deleted.lines)),
+        }
+    }
+    Ok(files)
+}
+
+/// Detect reverts, fixups and lines re-changed within `window_days` of being written
+///
+/// Merge commits are skipped so merged work isn't counted twice.
+pub fn analyze_rework(
+    repo: &Repository,
+    days: i64,
+    window_days: i64,
+    grouper: &PathGrouper,
+    interval: Interval,
+) -> Result<ReworkReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut contributor_map: HashMap<String, ReworkStats> = HashMap::new();
+    let mut directory_map: HashMap<(String, String), ReworkStats> = HashMap::new();
+    let mut timeline_map: HashMap<(String, String), ReworkStats> = HashMap::new();
+    let mut reverts = Vec::new();
+    let mut commit_times = HashMap::new();
+
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        if !is_included(&commit, repo, days) || commit.parent_count() > 1 {
+            continue;
+        }
+
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let name = author.name().unwrap_or("unknown").to_string();
+        let email = author.email().unwrap_or("unknown").to_string();
+        let message = commit.message().unwrap_or_default();
+
+        let mut stats = ReworkStats {
+            label: name.clone(),
+            email: email.clone(),
+            commits: 1,
+            fixups: usize::from(is_fixup(commit.summary().unwrap_or_default())),
+            ..Default::default()
+        };
+
+        if let Some(reverted) =
+            reverted_hash(message).and_then(|hash| repo.find_commit_by_prefix(hash).ok())
+        {
+            stats.reverts = 1;
+            let reverted_author = canonical_author(&reverted, mailmap.as_ref());
+            let reverted_name = reverted_author.name().unwrap_or("unknown").to_string();
+            let reverted_email = reverted_author.email().unwrap_or("unknown").to_string();
+            contributor_map
+                .entry(format!("{reverted_name}|{reverted_email}"))
+                .or_insert_with(|| ReworkStats {
+                    label: reverted_name.clone(),
+                    email: reverted_email.clone(),
+                    ..Default::default()
+                })
+                .reverted += 1;
+            reverts.push(RevertInfo {
+                hash: oid.to_string()[..7].to_string(),
+                reverted_hash: reverted.id().to_string()[..7].to_string(),
+                summary: commit.summary().unwrap_or_default().to_string(),
+                author: name.clone(),
+                email: email.clone(),
+                reverted_author: reverted_name,
+                days_to_revert: (time_to_datetime(commit.time())
+                    - time_to_datetime(reverted.time()))
+                .num_days(),
+            });
+        }
+
+        // Per-directory counts for this commit
+        let mut touched: HashMap<String, ReworkStats> = HashMap::new();
+        for file in super::get_file_line_stats(&commit, repo)? {
+            stats.additions += file.additions;
+            stats.deletions += file.deletions;
+            let dir = touched.entry(grouper.key(&file.path)).or_default();
+            dir.additions += file.additions;
+            dir.deletions += file.deletions;
+        }
+        for (path, lines) in rework_lines(&commit, repo, window_days, &mut commit_times)? {
+            stats.rework += lines;
+            touched.entry(grouper.key(&path)).or_default().rework += lines;
+        }
+
+        for (dir, mut dir_stats) in touched {
+            dir_stats.commits = 1;
+            dir_stats.fixups = stats.fixups;
+            dir_stats.reverts = stats.reverts;
+            directory_map.entry((dir, email.clone())).or_default().absorb(&dir_stats);
+        }
+
+        let bucket = interval.bucket(time_to_datetime(commit.time()));
+        timeline_map
+            .entry((bucket.clone(), email.clone()))
+            .or_insert_with(|| ReworkStats {
+                label: bucket,
+                email: email.clone(),
+                ..Default::default()
+            })
+            .absorb(&stats);
+
+        contributor_map
+            .entry(format!("{name}|{email}"))
+            .or_insert_with(|| ReworkStats {
+                label: name,
+                email,
+                ..Default::default()
+            })
+            .absorb(&stats);
+    }
+
+    let mut contributors: Vec<ReworkStats> = contributor_map.into_values().collect();
+    contributors.sort_by_key(|s| (Reverse(s.rework), Reverse(s.commits)));
+    let directories = directory_map
+        .into_iter()
+        .map(|((label, email), mut stats)| {
+            stats.label = label;
+            stats.email = email;
+            stats
+        })
+        .collect();
+    reverts.sort_by_key(|r| r.days_to_revert);
+
+    Ok(ReworkReport {
+        contributors,
+        directories,
+        timeline: timeline_map.into_values().collect(),
+        reverts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverted_hash() {
+        let message = "Revert \"feat: add x\"\n\nThis reverts commit \
+                       0123456789abcdef0123456789abcdef01234567.\n";
+        assert_eq!(reverted_hash(message), Some("0123456789abcdef0123456789abcdef01234567"));
+        assert_eq!(reverted_hash("This reverts commit 0123abc."), Some("0123abc"));
+        assert_eq!(reverted_hash("This reverts commit 0123."), None);
+        assert_eq!(reverted_hash("Revert \"feat: add x\""), None);
+    }
+
+    #[test]
+    fn test_is_fixup() {
+        assert!(is_fixup("fixup! feat: add x"));
+        assert!(is_fixup("squash! feat: add x"));
+        assert!(!is_fixup("fix: handle fixup! prefix"));
+    }
+}
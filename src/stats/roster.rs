@@ -0,0 +1,220 @@
+use super::{contributor::walk_contributors, time_to_datetime, ContributorStats, Interval};
+use chrono::Utc;
+use git2::{BlameOptions, ObjectType, Repository, TreeWalkMode, TreeWalkResult};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    path::Path,
+};
+
+/// Where a contributor stands, judged from their commit history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActivityStatus {
+    /// Committing at their usual pace
+    Active,
+    /// First commit is recent
+    Ramping,
+    /// Quiet lately, or committing well below their usual pace
+    Fading,
+    /// No commits for longer than the departure window
+    Departed,
+}
+
+impl fmt::Display for ActivityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Active => "active",
+            Self::Ramping => "ramping",
+            Self::Fading => "fading",
+            Self::Departed => "departed",
+        })
+    }
+}
+
+/// Activity windows, in days, that drive the classification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActivityWindows {
+    /// Recent window compared against each person's long-run commit rate
+    pub active: i64,
+    /// People whose first commit is this recent are still ramping up
+    pub ramping: i64,
+    /// People with no commit for this long have departed
+    pub departed: i64,
+}
+
+impl Default for ActivityWindows {
+    fn default() -> Self {
+        Self {
+            active: 30,
+            ramping: 90,
+            departed: 90,
+        }
+    }
+}
+
+impl ActivityWindows {
+    /// Classify someone first and last seen the given number of days ago
+    ///
+    /// Fading means no commit within the active window, or fewer than half the commits their
+    /// long-run rate predicts for it.
+    pub fn classify(
+        &self,
+        first_seen_days: i64,
+        last_seen_days: i64,
+        commits: usize,
+        recent_commits: usize,
+    ) -> ActivityStatus {
+        if last_seen_days > self.departed {
+            return ActivityStatus::Departed;
+        }
+        if first_seen_days <= self.ramping {
+            return ActivityStatus::Ramping;
+        }
+        let span = u64::try_from(first_seen_days.max(1)).unwrap_or(1);
+        let window = u64::try_from(self.active.max(1)).unwrap_or(1);
+        if last_seen_days > self.active
+            || recent_commits as u64 * 2 * span < commits as u64 * window
+        {
+            return ActivityStatus::Fading;
+        }
+        ActivityStatus::Active
+    }
+}
+
+/// One contributor's status and the code they still own
+#[derive(Debug, Clone)]
+pub struct RosterEntry {
+    pub stats: ContributorStats,
+    pub status: ActivityStatus,
+    /// Commits within the active window
+    pub recent_commits: usize,
+    /// Lines at HEAD last changed by this contributor, according to blame
+    pub owned_lines: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct RosterReport {
+    /// Most recently seen first
+    pub entries: Vec<RosterEntry>,
+    /// Distinct committers per period, oldest first
+    pub headcount: Vec<(String, usize)>,
+    /// Text lines at HEAD, for ownership shares
+    pub total_lines: usize,
+}
+
+/// Lines at HEAD per `name|email`, attributed by blame
+fn blame_ownership(repo: &Repository) -> Result<(HashMap<String, usize>, usize), git2::Error> {
+    let mut paths = Vec::new();
+    repo.head()?.peel_to_tree()?.walk(TreeWalkMode::PreOrder, |dir, entry| {
+        if entry.kind() == Some(ObjectType::Blob) {
+            let is_text = repo.find_blob(entry.id()).is_ok_and(|blob| !blob.is_binary());
+            if is_text {
+                paths.push(format!("{dir}{}", entry.name().unwrap_or_default()));
+            }
+        }
+        TreeWalkResult::Ok
+    })?;
+
+    let mut owned: HashMap<String, usize> = HashMap::new();
+    let mut total = 0;
+    // Same identities as walk_contributors, which applies .mailmap
+    let mut blame_opts = BlameOptions::new();
+    blame_opts.use_mailmap(true);
+    for path in paths {
+        let Ok(blame) = repo.blame_file(Path::new(&path), Some(&mut blame_opts)) else {
+            continue;
+        };
+        for hunk in blame.iter() {
+            let signature = hunk.final_signature();
+            let key = format!(
+                "{}|{}",
+                signature.name().unwrap_or("unknown"),
+                signature.email().unwrap_or("unknown")
+            );
+            *owned.entry(key).or_insert(0) += hunk.lines_in_hunk();
+            total += hunk.lines_in_hunk();
+        }
+    }
+    Ok((owned, total))
+}
+
+/// Classify every contributor and count committers per period over the whole history
+///
+/// Blame at HEAD is only run when someone has departed, since it reads every file.
+pub fn analyze_roster(
+    repo: &Repository,
+    windows: ActivityWindows,
+    interval: Interval,
+) -> Result<RosterReport, git2::Error> {
+    let now = Utc::now();
+    let mut recent: HashMap<String, usize> = HashMap::new();
+    let mut committers: BTreeMap<String, HashSet<String>> = BTreeMap::new();
+
+    let contributors = walk_contributors(repo, 0, |commit, key, _| {
+        let committed_at = time_to_datetime(commit.time());
+        if (now - committed_at).num_days() <= windows.active {
+            *recent.entry(key.to_string()).or_insert(0) += 1;
+        }
+        committers
+            .entry(interval.bucket(committed_at))
+            .or_default()
+            .insert(key.to_string());
+    })?;
+
+    let mut entries: Vec<RosterEntry> = contributors
+        .into_iter()
+        .map(|stats| {
+            let days_since =
+                |at: Option<chrono::DateTime<Utc>>| at.map_or(i64::MAX, |at| (now - at).num_days());
+            let recent_commits =
+                recent.get(&format!("{}|{}", stats.name, stats.email)).copied().unwrap_or(0);
+            let status = windows.classify(
+                days_since(stats.first_commit),
+                days_since(stats.last_commit),
+                stats.commits,
+                recent_commits,
+            );
+            RosterEntry {
+                stats,
+                status,
+                recent_commits,
+                owned_lines: 0,
+            }
+        })
+        .collect();
+
+    let total_lines = if entries.iter().any(|e| e.status == ActivityStatus::Departed) {
+        let (owned, total) = blame_ownership(repo)?;
+        for entry in &mut entries {
+            let key = format!("{}|{}", entry.stats.name, entry.stats.email);
+            entry.owned_lines = owned.get(&key).copied().unwrap_or(0);
+        }
+        total
+    } else {
+        0
+    };
+
+    entries.sort_by_key(|e| std::cmp::Reverse(e.stats.last_commit));
+    Ok(RosterReport {
+        entries,
+        headcount: committers.into_iter().map(|(period, keys)| (period, keys.len())).collect(),
+        total_lines,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        let windows = ActivityWindows::default();
+        // Steady: 100 commits over a year, 10 in the last month
+        assert_eq!(windows.classify(365, 2, 100, 10), ActivityStatus::Active);
+        assert_eq!(windows.classify(40, 2, 5, 5), ActivityStatus::Ramping);
+        // Slowed down: 1 commit in the last month against ~8 expected
+        assert_eq!(windows.classify(365, 5, 100, 1), ActivityStatus::Fading);
+        assert_eq!(windows.classify(365, 45, 100, 0), ActivityStatus::Fading);
+        assert_eq!(windows.classify(365, 120, 100, 0), ActivityStatus::Departed);
+    }
+}
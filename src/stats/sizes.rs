@@ -0,0 +1,262 @@
+use super::{
+    canonical_author, get_file_line_stats, is_formatting_only, is_within_days, median, percentile,
+    FileLineStats,
+};
+use git2::Repository;
+use std::{cmp::Reverse, collections::HashMap, fmt, path::Path};
+
+/// Histogram buckets: inclusive upper bound on lines changed, and label
+pub const SIZE_BUCKETS: [(usize, &str); 7] = [
+    (0, "0"),
+    (10, "1-10"),
+    (50, "11-50"),
+    (200, "51-200"),
+    (1000, "201-1000"),
+    (5000, "1001-5000"),
+    (usize::MAX, "5000+"),
+];
+
+/// Directories holding third-party code
+const VENDOR_DIRS: [&str; 4] = ["vendor", "node_modules", "third_party", "external"];
+
+/// Generated dependency lock files
+const LOCKFILES: [&str; 6] = [
+    "Cargo.lock",
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "go.sum",
+    "poetry.lock",
+];
+
+/// Commit sizes for one contributor
+#[derive(Debug, Default, Clone)]
+pub struct SizeStats {
+    pub name: String,
+    pub email: String,
+    /// Lines changed (added + deleted) by each commit
+    pub lines: Vec<usize>,
+    /// Files touched by each commit
+    pub files: Vec<usize>,
+}
+
+impl SizeStats {
+    pub fn commits(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn median_lines(&self) -> usize {
+        median(&self.lines)
+    }
+
+    pub fn p90_lines(&self) -> usize {
+        percentile(&self.lines, 90)
+    }
+
+    pub fn max_lines(&self) -> usize {
+        self.lines.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn median_files(&self) -> usize {
+        median(&self.files)
+    }
+
+    pub fn p90_files(&self) -> usize {
+        percentile(&self.files, 90)
+    }
+
+    pub fn max_files(&self) -> usize {
+        self.files.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// Likely cause of an outsized commit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlierReason {
+    /// Mostly third-party code
+    Vendored,
+    /// Mostly dependency lock files
+    Lockfile,
+    /// Only whitespace, line endings or blank lines changed
+    Formatting,
+    /// Roughly as many lines deleted as added: a move or rewrite
+    Rewrite,
+    Large,
+}
+
+impl fmt::Display for OutlierReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Vendored => "vendored",
+            Self::Lockfile => "lockfile",
+            Self::Formatting => "formatting only",
+            Self::Rewrite => "move/rewrite",
+            Self::Large => "large change",
+        })
+    }
+}
+
+/// A commit changing more lines than the outlier threshold
+#[derive(Debug, Clone)]
+pub struct OutlierCommit {
+    pub hash: String,
+    pub author: String,
+    pub email: String,
+    pub summary: String,
+    pub lines: usize,
+    pub files: usize,
+    pub reason: OutlierReason,
+}
+
+/// Commit size distributions and outliers
+#[derive(Debug, Default)]
+pub struct SizeReport {
+    pub contributors: Vec<SizeStats>,
+    /// Largest first
+    pub outliers: Vec<OutlierCommit>,
+}
+
+fn is_vendored(path: &str) -> bool {
+    Path::new(path)
+        .components()
+        .any(|component| VENDOR_DIRS.iter().any(|dir| component.as_os_str() == *dir))
+}
+
+fn is_lockfile(path: &str) -> bool {
+    Path::new(path)
+        .file_name()
+        .is_some_and(|name| LOCKFILES.iter().any(|lockfile| name == *lockfile))
+}
+
+/// Guess why a commit is so large from where its lines went
+pub fn classify_outlier(files: &[FileLineStats]) -> OutlierReason {
+    let lines = |filter: &dyn Fn(&FileLineStats) -> bool| -> usize {
+        files.iter().filter(|f| filter(f)).map(|f| f.additions + f.deletions).sum()
+    };
+    let total = lines(&|_| true);
+
+    if lines(&|f| is_vendored(&f.path)) * 2 > total {
+        return OutlierReason::Vendored;
+    }
+    if lines(&|f| is_lockfile(&f.path)) * 2 > total {
+        return OutlierReason::Lockfile;
+    }
+
+    let additions: usize = files.iter().map(|f| f.additions).sum();
+    let deletions: usize = files.iter().map(|f| f.deletions).sum();
+    // Within 20% of each other
+    if additions.min(deletions) * 5 >= additions.max(deletions) * 4 {
+        return OutlierReason::Rewrite;
+    }
+    OutlierReason::Large
+}
+
+/// Sizes of non-merge commits per contributor, flagging commits above `threshold` lines
+pub fn analyze_commit_sizes(
+    repo: &Repository,
+    days: i64,
+    threshold: usize,
+) -> Result<SizeReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut stats_map: HashMap<String, SizeStats> = HashMap::new();
+    let mut outliers = Vec::new();
+
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        // Outliers are what this report is about, so only the period applies here
+        if !is_within_days(commit.time(), days) || commit.parent_count() > 1 {
+            continue;
+        }
+
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let name = author.name().unwrap_or("unknown").to_string();
+        let email = author.email().unwrap_or("unknown").to_string();
+
+        let files = get_file_line_stats(&commit, repo)?;
+        let lines: usize = files.iter().map(|f| f.additions + f.deletions).sum();
+
+        let entry = stats_map.entry(format!("{name}|{email}")).or_insert_with(|| SizeStats {
+            name: name.clone(),
+            email: email.clone(),
+            ..Default::default()
+        });
+        entry.lines.push(lines);
+        entry.files.push(files.len());
+
+        if lines > threshold {
+            outliers.push(OutlierCommit {
+                hash: oid.to_string()[..7].to_string(),
+                author: name,
+                email,
+                summary: commit.summary().unwrap_or_default().to_string(),
+                lines,
+                files: files.len(),
+                reason: if is_formatting_only(&commit, repo)? {
+                    OutlierReason::Formatting
+                } else {
+                    classify_outlier(&files)
+                },
+            });
+        }
+    }
+
+    let mut contributors: Vec<SizeStats> = stats_map.into_values().collect();
+    contributors.sort_by_key(|s| Reverse(s.commits()));
+    outliers.sort_by_key(|o| Reverse(o.lines));
+    Ok(SizeReport {
+        contributors,
+        outliers,
+    })
+}
+
+/// Commits per histogram bucket, in `SIZE_BUCKETS` order
+pub fn size_histogram(lines: impl IntoIterator<Item = usize>) -> [usize; SIZE_BUCKETS.len()] {
+    let mut counts = [0; SIZE_BUCKETS.len()];
+    for size in lines {
+        if let Some(idx) = SIZE_BUCKETS.iter().position(|(max, _)| size <= *max) {
+            counts[idx] += 1;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use git2::{Delta, Oid};
+
+    fn file(path: &str, additions: usize, deletions: usize) -> FileLineStats {
+        FileLineStats {
+            path: path.to_string(),
+            blob: Oid::zero(),
+            status: Delta::Modified,
+            binary: false,
+            additions,
+            deletions,
+        }
+    }
+
+    #[test]
+    fn test_classify_outlier() {
+        let vendored = [file("vendor/lib/a.go", 9000, 0), file("main.go", 10, 2)];
+        assert_eq!(classify_outlier(&vendored), OutlierReason::Vendored);
+        let lockfile = [
+            file("web/package-lock.json", 4000, 3500),
+            file("web/package.json", 2, 1),
+        ];
+        assert_eq!(classify_outlier(&lockfile), OutlierReason::Lockfile);
+        assert_eq!(classify_outlier(&[file("src/a.rs", 1200, 1100)]), OutlierReason::Rewrite);
+        assert_eq!(classify_outlier(&[file("src/a.rs", 3000, 20)]), OutlierReason::Large);
+    }
+
+    #[test]
+    fn test_size_histogram() {
+        assert_eq!(size_histogram([0, 5, 10, 11, 999, 40_000]), [1, 2, 1, 0, 1, 0, 1]);
+    }
+}
@@ -0,0 +1,287 @@
+use super::{
+    canonical_author, deleted_line_origins, get_file_line_stats, grouping::rollup, is_included,
+    is_within_days, time_to_datetime, Interval, PathGrouper,
+};
+use chrono::{DateTime, Utc};
+use git2::{Oid, Repository};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+/// Ages, in days, at which added lines are checked for survival
+pub const CHECKPOINTS: [i64; 3] = [30, 90, 180];
+
+/// How many added lines lived to each checkpoint, for one contributor, team, directory or period
+#[derive(Debug, Default, Clone)]
+pub struct SurvivalStats {
+    pub label: String,
+    pub email: String,
+    pub added: usize,
+    /// Added lines old enough to be checked at each checkpoint
+    pub observed: [usize; CHECKPOINTS.len()],
+    /// Of those, lines still present at the checkpoint
+    pub survived: [usize; CHECKPOINTS.len()],
+    /// Added lines still present at HEAD
+    pub at_head: usize,
+}
+
+impl SurvivalStats {
+    /// Percent surviving to each checkpoint (`None` until some lines are old enough)
+    pub fn survival_rates(&self) -> [Option<usize>; CHECKPOINTS.len()] {
+        std::array::from_fn(|idx| (self.survived[idx] * 100).checked_div(self.observed[idx]))
+    }
+
+    /// Percent still present at HEAD
+    pub const fn head_rate(&self) -> Option<usize> {
+        (self.at_head * 100).checked_div(self.added)
+    }
+
+    fn absorb(&mut self, other: &Self) {
+        self.added += other.added;
+        for idx in 0..CHECKPOINTS.len() {
+            self.observed[idx] += other.observed[idx];
+            self.survived[idx] += other.survived[idx];
+        }
+        self.at_head += other.at_head;
+    }
+
+    /// Pool line counts per team, directory or period, ordered by key, so survival rates
+    /// are taken over all of the group's lines rather than averaged per contributor
+    pub fn rollup(stats: &[Self], key: impl Fn(&Self) -> String) -> Vec<Self> {
+        let new = |label| Self {
+            label,
+            ..Default::default()
+        };
+        rollup(stats, key, new, Self::absorb)
+    }
+}
+
+/// Code survival per contributor, per directory and per period the lines were written in
+#[derive(Debug, Default)]
+pub struct SurvivalReport {
+    pub contributors: Vec<SurvivalStats>,
+    /// One entry per directory and contributor, labeled with the directory
+    pub directories: Vec<SurvivalStats>,
+    /// One entry per period and contributor, labeled with the period
+    pub timeline: Vec<SurvivalStats>,
+}
+
+/// When some of a commit's lines were deleted, and how many
+type Deletion = (DateTime<Utc>, usize);
+
+/// Lines one commit added, per group
+struct Cohort {
+    name: String,
+    email: String,
+    bucket: String,
+    written_at: DateTime<Utc>,
+    added: HashMap<String, usize>,
+}
+
+/// Track the lines added in the period until they are deleted or reach HEAD
+///
+/// Deletions are traced back to the commit that wrote the lines with blame (see
+/// [`deleted_line_origins`]); lines still present are found by blaming HEAD. Lines are
+/// grouped by the path they were written at, so a rename counts as deleting the old lines.
+pub fn analyze_survival(
+    repo: &Repository,
+    days: i64,
+    grouper: &PathGrouper,
+    interval: Interval,
+) -> Result<SurvivalReport, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut cohorts: HashMap<Oid, Cohort> = HashMap::new();
+    let mut deaths: HashMap<(Oid, String), Vec<Deletion>> = HashMap::new();
+    let mut paths: HashSet<String> = HashSet::new();
+
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+
+        // Merges only repeat changes made on the merged branch
+        if !is_within_days(commit.time(), days) || commit.parent_count() > 1 {
+            continue;
+        }
+
+        let committed_at = time_to_datetime(commit.time());
+        for deleted in deleted_line_origins(&commit, repo)? {
+            deaths
+                .entry((deleted.origin, grouper.key(&deleted.path)))
+                .or_default()
+                .push((committed_at, deleted.lines));
+        }
+
+        // Outliers and reformats still end the lines they delete, but start no cohort
+        if !is_included(&commit, repo, days) {
+            continue;
+        }
+        let author = canonical_author(&commit, mailmap.as_ref());
+        let mut cohort = Cohort {
+            name: author.name().unwrap_or("unknown").to_string(),
+            email: author.email().unwrap_or("unknown").to_string(),
+            bucket: interval.bucket(committed_at),
+            written_at: committed_at,
+            added: HashMap::new(),
+        };
+        for file in get_file_line_stats(&commit, repo)? {
+            if file.additions > 0 {
+                *cohort.added.entry(grouper.key(&file.path)).or_insert(0) += file.additions;
+                paths.insert(file.path);
+            }
+        }
+        cohorts.insert(oid, cohort);
+    }
+
+    // Lines written in the period that are still at HEAD
+    let mut alive: HashMap<(Oid, String), usize> = HashMap::new();
+    let head_tree = repo.head()?.peel_to_tree()?;
+    for file_path in &paths {
+        if head_tree.get_path(Path::new(file_path)).is_err() {
+            continue;
+        }
+        let Ok(blame) = repo.blame_file(Path::new(file_path), None) else {
+            continue;
+        };
+        for hunk in blame.iter() {
+            if cohorts.contains_key(&hunk.final_commit_id()) {
+                *alive.entry((hunk.final_commit_id(), grouper.key(file_path))).or_insert(0) +=
+                    hunk.lines_in_hunk();
+            }
+        }
+    }
+
+    let now = Utc::now();
+    let mut contributor_map: HashMap<String, SurvivalStats> = HashMap::new();
+    let mut directory_map: HashMap<(String, String), SurvivalStats> = HashMap::new();
+    let mut timeline_map: HashMap<(String, String), SurvivalStats> = HashMap::new();
+
+    for (oid, cohort) in cohorts {
+        let age = (now - cohort.written_at).num_days();
+        for (group, added) in cohort.added {
+            let key = (oid, group);
+            let group_deaths = deaths.get(&key).map_or(&[][..], Vec::as_slice);
+
+            let mut stats = SurvivalStats {
+                added,
+                at_head: alive.get(&key).copied().unwrap_or(0).min(added),
+                ..Default::default()
+            };
+            for (idx, checkpoint) in CHECKPOINTS.into_iter().enumerate() {
+                if age < checkpoint {
+                    continue;
+                }
+                let deleted: usize = group_deaths
+                    .iter()
+                    .filter(|(deleted_at, _)| {
+                        (*deleted_at - cohort.written_at).num_days() <= checkpoint
+                    })
+                    .map(|(_, lines)| lines)
+                    .sum();
+                stats.observed[idx] = added;
+                stats.survived[idx] = added.saturating_sub(deleted);
+            }
+
+            let (_, group) = key;
+            directory_map
+                .entry((group.clone(), cohort.email.clone()))
+                .or_insert_with(|| SurvivalStats {
+                    label: group,
+                    email: cohort.email.clone(),
+                    ..Default::default()
+                })
+                .absorb(&stats);
+            timeline_map
+                .entry((cohort.bucket.clone(), cohort.email.clone()))
+                .or_insert_with(|| SurvivalStats {
+                    label: cohort.bucket.clone(),
+                    email: cohort.email.clone(),
+                    ..Default::default()
+                })
+                .absorb(&stats);
+            contributor_map
+                .entry(format!("{}|{}", cohort.name, cohort.email))
+                .or_insert_with(|| SurvivalStats {
+                    label: cohort.name.clone(),
+                    email: cohort.email.clone(),
+                    ..Default::default()
+                })
+                .absorb(&stats);
+        }
+    }
+
+    let mut contributors: Vec<SurvivalStats> = contributor_map.into_values().collect();
+    contributors.sort_by_key(|s| Reverse(s.added));
+    Ok(SurvivalReport {
+        contributors,
+        directories: directory_map.into_values().collect(),
+        timeline: timeline_map.into_values().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{
+        tests::{commit, temp_repo, TEST_OPTIONS},
+        AnalysisOptions, GroupBy,
+    };
+    use std::{error::Error, fs};
+
+    #[test]
+    fn test_survival_rates() {
+        let stats = SurvivalStats {
+            added: 200,
+            observed: [200, 100, 0],
+            survived: [150, 50, 0],
+            at_head: 40,
+            ..Default::default()
+        };
+        assert_eq!(stats.survival_rates(), [Some(75), Some(50), None]);
+        assert_eq!(stats.head_rate(), Some(20));
+        assert_eq!(SurvivalStats::default().head_rate(), None);
+    }
+
+    #[test]
+    fn test_outlier_deletions_end_lines() -> Result<(), Box<dyn Error>> {
+        TEST_OPTIONS.with(|options| {
+            options.set(Some(AnalysisOptions {
+                max_commit_lines: Some(100),
+                ..Default::default()
+            }));
+        });
+        let (dir, repo) = temp_repo("survival-outlier")?;
+        let lines = |range: std::ops::Range<usize>| -> Vec<u8> {
+            range
+                .map(|idx| format!("line {idx}\n"))
+                .collect::<Vec<_>>()
+                .concat()
+                .into_bytes()
+        };
+        let day = 86_400;
+        let now = Utc::now().timestamp();
+
+        let written = commit(&repo, now - 200 * day, &[], &[("a.txt", &lines(0..10))])?;
+        // A big rewrite keeps 2 of the 10 lines and adds 200 new ones
+        let rewrite = commit(&repo, now - 190 * day, &[written], &[("a.txt", &lines(8..210))])?;
+        repo.reference("refs/heads/main", rewrite, true, "test")?;
+        repo.set_head("refs/heads/main")?;
+
+        let grouper = PathGrouper::new(&repo, GroupBy::File)?;
+        let report = analyze_survival(&repo, 0, &grouper, Interval::Month)?;
+        assert_eq!(report.contributors.len(), 1);
+        let stats = &report.contributors[0];
+        assert_eq!(stats.added, 10);
+        assert_eq!(stats.survived, [2, 2, 2]);
+        assert_eq!(stats.at_head, 2);
+
+        drop(repo);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -1,12 +1,26 @@
-use super::{is_within_days, time_to_datetime};
-use chrono::{Datelike, Local, Timelike};
-use git2::Repository;
-use std::collections::HashMap;
+use super::{canonical_author, get_file_line_stats, is_included, time_to_datetime};
+use chrono::{DateTime, Datelike, Local, NaiveDate, Timelike, Utc};
+use git2::{Commit, Repository, Signature};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[derive(Debug, Default)]
 pub struct TimeStats {
     pub hour_distribution: HashMap<u32, usize>,
     pub day_distribution: HashMap<u32, usize>,
+    /// Commits per calendar day, for the contribution calendar
+    pub daily: BTreeMap<NaiveDate, usize>,
+    /// Commits per weekday (Monday first) and hour, for heatmaps
+    pub weekday_hours: [[usize; 24]; 7],
+}
+
+impl TimeStats {
+    /// Count one commit made at `dt`
+    pub fn record(&mut self, dt: DateTime<Local>) {
+        *self.hour_distribution.entry(dt.hour()).or_insert(0) += 1;
+        *self.day_distribution.entry(dt.weekday().num_days_from_monday()).or_insert(0) += 1;
+        *self.daily.entry(dt.date_naive()).or_insert(0) += 1;
+        self.weekday_hours[dt.weekday().num_days_from_monday() as usize][dt.hour() as usize] += 1;
+    }
 }
 
 pub fn analyze_time_distribution(repo: &Repository, days: i64) -> Result<TimeStats, git2::Error> {
@@ -19,17 +33,116 @@ pub fn analyze_time_distribution(repo: &Repository, days: i64) -> Result<TimeSta
         let oid = oid?;
         let commit = repo.find_commit(oid)?;
 
-        if !is_within_days(commit.time(), days) {
+        if !is_included(&commit, repo, days) {
             continue;
         }
 
-        let dt = time_to_datetime(commit.time()).with_timezone(&Local);
-        let hour = dt.hour();
-        let day = dt.weekday().num_days_from_monday();
-
-        *time_stats.hour_distribution.entry(hour).or_insert(0) += 1;
-        *time_stats.day_distribution.entry(day).or_insert(0) += 1;
+        time_stats.record(time_to_datetime(commit.time()).with_timezone(&Local));
     }
 
     Ok(time_stats)
 }
+
+/// Bucket size for time series
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Interval {
+    /// ISO weeks, e.g. 2024-W07
+    Week,
+    /// Calendar months, e.g. 2024-02
+    #[default]
+    Month,
+}
+
+impl Interval {
+    /// Label of the bucket a timestamp falls into (local time)
+    pub fn bucket(self, dt: DateTime<Utc>) -> String {
+        let local = dt.with_timezone(&Local);
+        match self {
+            Self::Week => local.format("%G-W%V").to_string(),
+            Self::Month => local.format("%Y-%m").to_string(),
+        }
+    }
+}
+
+/// Commits, churn and distinct authors in one period
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeriodActivity {
+    pub label: String,
+    pub commits: usize,
+    /// Lines added plus deleted, merges left out
+    pub churn: usize,
+    pub authors: usize,
+}
+
+/// Commits, churn and distinct authors per period, built up one commit at a time
+///
+/// Shared by the trends analysis and the dashboard, so both bucket and count the same way.
+#[derive(Debug, Default)]
+pub struct ActivityTally {
+    interval: Interval,
+    periods: BTreeMap<String, (PeriodActivity, HashSet<String>)>,
+}
+
+impl ActivityTally {
+    pub const fn new(interval: Interval) -> Self {
+        Self {
+            interval,
+            periods: BTreeMap::new(),
+        }
+    }
+
+    /// Count one commit by `author` (already resolved through .mailmap); merges add no churn,
+    /// as they only repeat the merged branch's changes
+    pub fn record(&mut self, commit: &Commit, author: &Signature, churn: usize) {
+        let label = self.interval.bucket(time_to_datetime(commit.time()));
+        let (period, authors) = self.periods.entry(label).or_default();
+        period.commits += 1;
+        if commit.parent_count() <= 1 {
+            period.churn += churn;
+        }
+        authors.insert(author.email().unwrap_or("unknown").to_string());
+    }
+
+    /// Oldest period first
+    pub fn finish(self) -> Vec<PeriodActivity> {
+        self.periods
+            .into_iter()
+            .map(|(label, (period, authors))| PeriodActivity {
+                label,
+                authors: authors.len(),
+                ..period
+            })
+            .collect()
+    }
+}
+
+/// Activity per period over the last `days` days, oldest period first
+pub fn analyze_activity(
+    repo: &Repository,
+    days: i64,
+    interval: Interval,
+) -> Result<Vec<PeriodActivity>, git2::Error> {
+    let mailmap = repo.mailmap().ok();
+    let mut tally = ActivityTally::new(interval);
+    let mut revwalk = repo.revwalk()?;
+    // Analyze all branches, not just HEAD
+    revwalk.push_glob("refs/*")?;
+
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if !is_included(&commit, repo, days) {
+            continue;
+        }
+
+        // Merges add no churn, so skip their diffs
+        let churn = if commit.parent_count() <= 1 {
+            let files = get_file_line_stats(&commit, repo)?;
+            files.iter().map(|f| f.additions + f.deletions).sum()
+        } else {
+            0
+        };
+        tally.record(&commit, &canonical_author(&commit, mailmap.as_ref()), churn);
+    }
+
+    Ok(tally.finish())
+}
@@ -0,0 +1,489 @@
+use super::data::{Dataset, Filter};
+use crate::stats::{ContributorStats, FileStats, Interval};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::widgets::TableState;
+use std::cmp::Ordering;
+
+/// Date ranges `d` cycles through, in days (0 = all time)
+const DAY_PRESETS: [i64; 6] = [7, 30, 90, 180, 365, 0];
+
+/// Rows skipped by Page Up / Page Down
+const PAGE: isize = 10;
+
+pub const CONTRIBUTOR_COLUMNS: [&str; 8] = [
+    "Contributor",
+    "Commits",
+    "Additions",
+    "Deletions",
+    "Files",
+    "Net",
+    "Active Days",
+    "Last Commit",
+];
+
+pub const FILE_COLUMNS: [&str; 6] = [
+    "Path",
+    "Changes",
+    "Added",
+    "Deleted",
+    "Churn",
+    "Contributors",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    Summary,
+    Contributors,
+    Time,
+    Files,
+    Trends,
+}
+
+impl Tab {
+    pub const ALL: [Self; 5] = [
+        Self::Summary,
+        Self::Contributors,
+        Self::Time,
+        Self::Files,
+        Self::Trends,
+    ];
+
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Summary => "Summary",
+            Self::Contributors => "Contributors",
+            Self::Time => "Time",
+            Self::Files => "Files",
+            Self::Trends => "Trends",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|tab| *tab == self).unwrap_or(0)
+    }
+}
+
+/// What the keyboard currently drives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    Browse,
+    /// Files and commits of one contributor, by `name|email`
+    Drill(String),
+    /// Typing a path filter
+    PathInput(String),
+}
+
+/// Column and direction a table is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sort {
+    pub column: usize,
+    pub descending: bool,
+}
+
+pub struct App {
+    pub tab: Tab,
+    pub mode: Mode,
+    pub filter: Filter,
+    /// `GIT_TEAMS` entries `t` cycles through, after "all teams"
+    pub teams: Vec<String>,
+    pub data: Dataset,
+    pub contributor_sort: Sort,
+    pub file_sort: Sort,
+    pub contributors: TableState,
+    pub files: TableState,
+    pub commits: TableState,
+    pub quit: bool,
+}
+
+fn compare_contributors(a: &ContributorStats, b: &ContributorStats, column: usize) -> Ordering {
+    let net = |s: &ContributorStats| s.additions as i128 - s.deletions as i128;
+    match column {
+        0 => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        1 => a.commits.cmp(&b.commits),
+        2 => a.additions.cmp(&b.additions),
+        3 => a.deletions.cmp(&b.deletions),
+        4 => a.files_changed.cmp(&b.files_changed),
+        5 => net(a).cmp(&net(b)),
+        6 => a.active_days.cmp(&b.active_days),
+        _ => a.last_commit.cmp(&b.last_commit),
+    }
+}
+
+fn compare_files(a: &FileStats, b: &FileStats, column: usize) -> Ordering {
+    match column {
+        0 => a.path.cmp(&b.path),
+        1 => a.changes.cmp(&b.changes),
+        2 => a.additions.cmp(&b.additions),
+        3 => a.deletions.cmp(&b.deletions),
+        4 => a.churn().cmp(&b.churn()),
+        _ => a.contributors.len().cmp(&b.contributors.len()),
+    }
+}
+
+/// Move a table selection by `delta` rows, staying within `len`
+fn step(state: &mut TableState, len: usize, delta: isize) {
+    if len == 0 {
+        state.select(None);
+        return;
+    }
+    let current = state.selected().unwrap_or(0);
+    let next = current.saturating_add_signed(delta).min(len - 1);
+    state.select(Some(next));
+}
+
+impl App {
+    pub fn new(filter: Filter, teams: Vec<String>) -> Self {
+        Self {
+            tab: Tab::Summary,
+            mode: Mode::Browse,
+            filter,
+            teams,
+            data: Dataset::default(),
+            contributor_sort: Sort {
+                column: 1,
+                descending: true,
+            },
+            file_sort: Sort {
+                column: 4,
+                descending: true,
+            },
+            contributors: TableState::default(),
+            files: TableState::default(),
+            commits: TableState::default(),
+            quit: false,
+        }
+    }
+
+    /// Swap in freshly loaded data, keeping the current sort orders
+    pub fn set_data(&mut self, data: Dataset) {
+        self.data = data;
+        self.sort_contributors();
+        self.sort_files();
+        self.contributors.select((!self.data.contributors.is_empty()).then_some(0));
+        self.files.select((!self.data.files.is_empty()).then_some(0));
+        if matches!(self.mode, Mode::Drill(_)) {
+            self.mode = Mode::Browse;
+        }
+    }
+
+    fn sort_contributors(&mut self) {
+        let Sort { column, descending } = self.contributor_sort;
+        self.data.contributors.sort_by(|a, b| {
+            let order = compare_contributors(a, b, column);
+            if descending {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+    }
+
+    fn sort_files(&mut self) {
+        let Sort { column, descending } = self.file_sort;
+        self.data.files.sort_by(|a, b| {
+            let order = compare_files(a, b, column);
+            if descending {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+    }
+
+    pub fn selected_contributor(&self) -> Option<&ContributorStats> {
+        self.contributors.selected().and_then(|idx| self.data.contributors.get(idx))
+    }
+
+    /// Handle one key press; returns true when the filter changed and data must be reloaded
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.quit = true;
+            return false;
+        }
+        match self.mode.clone() {
+            Mode::PathInput(buffer) => self.handle_path_input(key.code, buffer),
+            Mode::Drill(_) => {
+                match key.code {
+                    KeyCode::Esc | KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') => {
+                        self.mode = Mode::Browse;
+                    }
+                    KeyCode::Char('q') => self.quit = true,
+                    code => self.move_selection(code),
+                }
+                false
+            }
+            Mode::Browse => self.handle_browse(key.code),
+        }
+    }
+
+    fn handle_path_input(&mut self, code: KeyCode, mut buffer: String) -> bool {
+        match code {
+            KeyCode::Enter => {
+                self.mode = Mode::Browse;
+                if buffer != self.filter.path {
+                    self.filter.path = buffer;
+                    return true;
+                }
+            }
+            KeyCode::Esc => self.mode = Mode::Browse,
+            KeyCode::Backspace => {
+                buffer.pop();
+                self.mode = Mode::PathInput(buffer);
+            }
+            KeyCode::Char(c) => {
+                buffer.push(c);
+                self.mode = Mode::PathInput(buffer);
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn handle_browse(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
+                self.tab = Tab::ALL[(self.tab.index() + 1) % Tab::ALL.len()];
+            }
+            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+                self.tab = Tab::ALL[(self.tab.index() + Tab::ALL.len() - 1) % Tab::ALL.len()];
+            }
+            KeyCode::Char(c @ '1'..='5') => {
+                self.tab = Tab::ALL[c as usize - '1' as usize];
+            }
+            KeyCode::Char('s') => self.next_sort_column(),
+            KeyCode::Char('r') => self.reverse_sort(),
+            KeyCode::Enter => return self.drill_down(),
+            KeyCode::Char('d') => return self.cycle_days(1),
+            KeyCode::Char('D') => return self.cycle_days(DAY_PRESETS.len() - 1),
+            KeyCode::Char('t') => return self.cycle_team(),
+            KeyCode::Char('i') => {
+                self.filter.interval = match self.filter.interval {
+                    Interval::Week => Interval::Month,
+                    Interval::Month => Interval::Week,
+                };
+                return true;
+            }
+            KeyCode::Char('/') => self.mode = Mode::PathInput(self.filter.path.clone()),
+            KeyCode::Char('c') if !self.filter.path.is_empty() => {
+                self.filter.path.clear();
+                return true;
+            }
+            code => self.move_selection(code),
+        }
+        false
+    }
+
+    fn move_selection(&mut self, code: KeyCode) {
+        let delta: isize = match code {
+            KeyCode::Up | KeyCode::Char('k') => -1,
+            KeyCode::Down | KeyCode::Char('j') => 1,
+            KeyCode::PageUp => -PAGE,
+            KeyCode::PageDown => PAGE,
+            KeyCode::Home | KeyCode::Char('g') => isize::MIN,
+            KeyCode::End | KeyCode::Char('G') => isize::MAX,
+            _ => return,
+        };
+        match &self.mode {
+            Mode::Drill(key) => {
+                let len = self.data.commits_by_author.get(key).map_or(0, Vec::len);
+                step(&mut self.commits, len, delta);
+            }
+            _ => match self.tab {
+                Tab::Contributors => {
+                    step(&mut self.contributors, self.data.contributors.len(), delta);
+                }
+                Tab::Files => step(&mut self.files, self.data.files.len(), delta),
+                _ => {}
+            },
+        }
+    }
+
+    fn next_sort_column(&mut self) {
+        match self.tab {
+            Tab::Contributors => {
+                let column = (self.contributor_sort.column + 1) % CONTRIBUTOR_COLUMNS.len();
+                // Names read best A-Z, numbers largest first
+                self.contributor_sort = Sort {
+                    column,
+                    descending: column != 0,
+                };
+                self.sort_contributors();
+            }
+            Tab::Files => {
+                let column = (self.file_sort.column + 1) % FILE_COLUMNS.len();
+                self.file_sort = Sort {
+                    column,
+                    descending: column != 0,
+                };
+                self.sort_files();
+            }
+            _ => {}
+        }
+    }
+
+    fn reverse_sort(&mut self) {
+        match self.tab {
+            Tab::Contributors => {
+                self.contributor_sort.descending = !self.contributor_sort.descending;
+                self.sort_contributors();
+            }
+            Tab::Files => {
+                self.file_sort.descending = !self.file_sort.descending;
+                self.sort_files();
+            }
+            _ => {}
+        }
+    }
+
+    /// Enter: open a contributor's files and commits, or narrow the path filter to a file
+    fn drill_down(&mut self) -> bool {
+        match self.tab {
+            Tab::Contributors => {
+                if let Some(stat) = self.selected_contributor() {
+                    self.mode = Mode::Drill(format!("{}|{}", stat.name, stat.email));
+                    self.commits.select(Some(0));
+                }
+                false
+            }
+            Tab::Files => {
+                let Some(file) = self.files.selected().and_then(|idx| self.data.files.get(idx))
+                else {
+                    return false;
+                };
+                self.filter.path = file.path.clone();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn cycle_days(&mut self, offset: usize) -> bool {
+        let current = DAY_PRESETS.iter().position(|days| *days == self.filter.days).unwrap_or(0);
+        self.filter.days = DAY_PRESETS[(current + offset) % DAY_PRESETS.len()];
+        true
+    }
+
+    fn cycle_team(&mut self) -> bool {
+        if self.teams.is_empty() {
+            return false;
+        }
+        // None, then each team in order, then back to None
+        let next = match &self.filter.team {
+            None => Some(0),
+            Some(team) => self
+                .teams
+                .iter()
+                .position(|t| t == team)
+                .map(|idx| idx + 1)
+                .filter(|idx| *idx < self.teams.len()),
+        };
+        self.filter.team = next.map(|idx| self.teams[idx].clone());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(app: &mut App, code: KeyCode) -> bool {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn app_with(contributors: usize) -> App {
+        let mut app = App::new(Filter::default(), Vec::new());
+        app.set_data(Dataset {
+            contributors: (0..contributors)
+                .map(|idx| ContributorStats {
+                    name: format!("dev{idx}"),
+                    email: format!("dev{idx}@example.com"),
+                    commits: contributors - idx,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        });
+        app
+    }
+
+    #[test]
+    fn test_tab_switching_wraps() {
+        let mut app = app_with(0);
+        assert_eq!(app.tab, Tab::Summary);
+        press(&mut app, KeyCode::BackTab);
+        assert_eq!(app.tab, Tab::Trends);
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.tab, Tab::Summary);
+        press(&mut app, KeyCode::Char('l'));
+        assert_eq!(app.tab, Tab::Contributors);
+        press(&mut app, KeyCode::Char('4'));
+        assert_eq!(app.tab, Tab::Files);
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.tab, Tab::Time);
+        assert!(!app.quit);
+    }
+
+    #[test]
+    fn test_scroll_stays_in_bounds() {
+        let mut app = app_with(3);
+        press(&mut app, KeyCode::Char('2'));
+        assert_eq!(app.contributors.selected(), Some(0));
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.contributors.selected(), Some(0));
+        press(&mut app, KeyCode::PageDown);
+        assert_eq!(app.contributors.selected(), Some(2));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.contributors.selected(), Some(2));
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(app.contributors.selected(), Some(0));
+        press(&mut app, KeyCode::End);
+        assert_eq!(app.contributors.selected(), Some(2));
+
+        // Nothing to select in an empty table
+        let mut empty = app_with(0);
+        press(&mut empty, KeyCode::Char('2'));
+        press(&mut empty, KeyCode::Down);
+        assert_eq!(empty.contributors.selected(), None);
+        assert!(!press(&mut empty, KeyCode::Enter));
+        assert_eq!(empty.mode, Mode::Browse);
+    }
+
+    #[test]
+    fn test_drill_down_and_back() {
+        let mut app = app_with(2);
+        press(&mut app, KeyCode::Char('2'));
+        press(&mut app, KeyCode::Down);
+        assert!(!press(&mut app, KeyCode::Enter));
+        assert_eq!(app.mode, Mode::Drill("dev1|dev1@example.com".to_string()));
+        // Left leaves the drill-down instead of switching tabs
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.mode, Mode::Browse);
+        assert_eq!(app.tab, Tab::Contributors);
+    }
+
+    #[test]
+    fn test_path_input_reloads_only_on_change() {
+        let mut app = app_with(0);
+        press(&mut app, KeyCode::Char('/'));
+        for c in "srcx".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.mode, Mode::PathInput("src".to_string()));
+        assert!(press(&mut app, KeyCode::Enter));
+        assert_eq!(app.filter.path, "src");
+
+        press(&mut app, KeyCode::Char('/'));
+        assert!(!press(&mut app, KeyCode::Enter));
+        assert!(press(&mut app, KeyCode::Char('c')));
+        assert!(app.filter.path.is_empty());
+
+        // q is typed into the filter, Ctrl+C still quits
+        press(&mut app, KeyCode::Char('/'));
+        press(&mut app, KeyCode::Char('q'));
+        assert!(!app.quit);
+        app.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(app.quit);
+    }
+}
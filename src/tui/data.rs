@@ -0,0 +1,157 @@
+use crate::stats::{
+    contributor::ContributorTally, files::walk_file_changes, sort_files, time::ActivityTally,
+    time_to_datetime, ContributorStats, FileSort, FileStats, Interval, PathGrouper, PeriodActivity,
+    TimeStats,
+};
+use chrono::{DateTime, Local, Utc};
+use git2::Repository;
+use std::{cmp::Reverse, collections::HashMap};
+
+/// Date range, team and path the dashboard is narrowed to
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    /// Number of days to analyze (0 = all time)
+    pub days: i64,
+    /// `GIT_TEAMS` entry the author's email must contain
+    pub team: Option<String>,
+    /// Path prefix changed files must start with (empty = whole repository)
+    pub path: String,
+    pub interval: Interval,
+}
+
+impl Filter {
+    /// Whether an author passes the team filter: the selected team, or else any of `teams`
+    pub fn includes_email(&self, email: &str, teams: &[String]) -> bool {
+        let teams = self.team.as_ref().map_or(teams, std::slice::from_ref);
+        teams.is_empty() || teams.iter().any(|team| email.contains(team.as_str()))
+    }
+}
+
+/// One commit in a contributor's drill-down
+#[derive(Debug, Clone)]
+pub struct CommitRow {
+    pub hash: String,
+    pub date: DateTime<Utc>,
+    pub summary: String,
+    /// Lines changed within the path filter
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+/// Everything the dashboard shows for one filter
+#[derive(Debug, Default)]
+pub struct Dataset {
+    pub contributors: Vec<ContributorStats>,
+    pub files: Vec<FileStats>,
+    /// Commits by hour and weekday, in local time
+    pub time: TimeStats,
+    /// Oldest period first
+    pub trends: Vec<PeriodActivity>,
+    /// Files each contributor touched, keyed by `name|email`, most churn first
+    pub files_by_author: HashMap<String, Vec<FileStats>>,
+    /// Commits of each contributor, keyed by `name|email`, newest first
+    pub commits_by_author: HashMap<String, Vec<CommitRow>>,
+}
+
+fn record_file(
+    map: &mut HashMap<String, FileStats>,
+    path: &str,
+    lines: (usize, usize),
+    author: &str,
+) {
+    let entry = map.entry(path.to_string()).or_insert_with(|| FileStats {
+        path: path.to_string(),
+        ..Default::default()
+    });
+    entry.changes += 1;
+    entry.additions += lines.0;
+    entry.deletions += lines.1;
+    if !entry.contributors.iter().any(|c| c == author) {
+        entry.contributors.push(author.to_string());
+    }
+}
+
+/// Walk the history once and build every tab's data, counting only changes that pass `filter`
+///
+/// Without a selected team, authors outside `teams` (`GIT_TEAMS`) are left out like in the
+/// other commands. With a path filter, commits that touch nothing under the path are left out
+/// entirely. Totals go through the same tallies as `contributors`, `time-analysis` and `trends`.
+pub fn load(repo: &Repository, filter: &Filter, teams: &[String]) -> Result<Dataset, git2::Error> {
+    let mut data = Dataset::default();
+    let mut contributors = ContributorTally::default();
+    let mut activity = ActivityTally::new(filter.interval);
+    let mut file_map: HashMap<String, FileStats> = HashMap::new();
+    let mut author_files: HashMap<String, HashMap<String, FileStats>> = HashMap::new();
+
+    walk_file_changes(repo, filter.days, &PathGrouper::default(), |commit, author, files| {
+        let name = author.name().unwrap_or("unknown");
+        let email = author.email().unwrap_or("unknown");
+        if !filter.includes_email(email, teams) {
+            return;
+        }
+        let files: Vec<_> = files.iter().filter(|f| f.path.starts_with(&filter.path)).collect();
+        if files.is_empty() && !filter.path.is_empty() {
+            return;
+        }
+
+        let additions: usize = files.iter().map(|f| f.additions).sum();
+        let deletions: usize = files.iter().map(|f| f.deletions).sum();
+        let key = contributors.record(commit, author, (additions, deletions, files.len()));
+        activity.record(commit, author, additions + deletions);
+        let committed_at = time_to_datetime(commit.time());
+        data.time.record(committed_at.with_timezone(&Local));
+
+        let touched = author_files.entry(key.clone()).or_default();
+        for file in &files {
+            let lines = (file.additions, file.deletions);
+            record_file(&mut file_map, &file.path, lines, name);
+            record_file(touched, &file.path, lines, name);
+        }
+
+        data.commits_by_author.entry(key).or_default().push(CommitRow {
+            hash: commit.id().to_string()[..7].to_string(),
+            date: committed_at,
+            summary: commit.summary().unwrap_or_default().to_string(),
+            additions,
+            deletions,
+        });
+    })?;
+
+    data.contributors = contributors.finish();
+    data.trends = activity.finish();
+    data.files = file_map.into_values().collect();
+    sort_files(&mut data.files, FileSort::Churn);
+    data.files_by_author = author_files
+        .into_iter()
+        .map(|(key, files)| {
+            let mut files: Vec<FileStats> = files.into_values().collect();
+            sort_files(&mut files, FileSort::Churn);
+            (key, files)
+        })
+        .collect();
+    for commits in data.commits_by_author.values_mut() {
+        commits.sort_by_key(|c| Reverse(c.date));
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_team_filter() {
+        let teams = ["acme.com".to_string(), "partner.org".to_string()];
+        let all = Filter::default();
+        assert!(all.includes_email("alice@acme.com", &teams));
+        assert!(!all.includes_email("mallory@example.com", &teams));
+        assert!(all.includes_email("mallory@example.com", &[]));
+
+        let partner = Filter {
+            team: Some("partner.org".to_string()),
+            ..Filter::default()
+        };
+        assert!(partner.includes_email("carol@partner.org", &teams));
+        assert!(!partner.includes_email("alice@acme.com", &teams));
+    }
+}
@@ -0,0 +1,91 @@
+//! Interactive terminal dashboard (`gtct tui`)
+
+mod app;
+mod data;
+mod ui;
+
+use crate::{config::Config, stats::Interval};
+use app::App;
+use crossterm::{
+    event::{self, Event, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use data::Filter;
+use git2::Repository;
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::Alignment,
+    widgets::{Block, Borders, Paragraph},
+    Terminal,
+};
+use std::{error::Error, io};
+
+/// Restores the terminal when the dashboard exits, including on errors and panics
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        if let Err(e) = execute!(io::stdout(), EnterAlternateScreen) {
+            let _ = disable_raw_mode();
+            return Err(e);
+        }
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+    }
+}
+
+/// Reload the dataset for the current filter, showing a notice while the history is walked
+fn reload(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    repo: &Repository,
+    app: &mut App,
+) -> Result<(), Box<dyn Error>> {
+    terminal.draw(|frame| {
+        let notice = Paragraph::new("Analyzing history…")
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(notice, frame.size());
+    })?;
+    let data = data::load(repo, &app.filter, &app.teams)?;
+    app.set_data(data);
+    Ok(())
+}
+
+/// Run the dashboard until the user quits
+pub fn run(
+    repo: &Repository,
+    config: &Config,
+    days: i64,
+    interval: Interval,
+) -> Result<(), Box<dyn Error>> {
+    let filter = Filter {
+        days,
+        interval,
+        ..Filter::default()
+    };
+    let mut app = App::new(filter, config.teams.clone());
+
+    let _guard = TerminalGuard::enter()?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    terminal.clear()?;
+    reload(&mut terminal, repo, &mut app)?;
+
+    while !app.quit {
+        terminal.draw(|frame| ui::draw(frame, &mut app))?;
+        if let Event::Key(key) = event::read()? {
+            // Windows also reports releases; only act on presses
+            if key.kind == KeyEventKind::Press && app.handle_key(key) {
+                reload(&mut terminal, repo, &mut app)?;
+            }
+        }
+    }
+    Ok(())
+}
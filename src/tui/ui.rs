@@ -0,0 +1,379 @@
+use super::app::{App, Mode, Sort, Tab, CONTRIBUTOR_COLUMNS, FILE_COLUMNS};
+use crate::{
+    display::format::format_date,
+    stats::{ContributorStats, FileStats, Interval},
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{BarChart, Block, Borders, Cell, Paragraph, Row, Table, Tabs},
+    Frame,
+};
+use std::{cmp::Reverse, collections::HashMap};
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Heatmap shades from no commits to the busiest hour
+const SHADES: [&str; 5] = ["  ", "░░", "▒▒", "▓▓", "██"];
+
+fn title_style() -> Style {
+    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+}
+
+fn header_style() -> Style {
+    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+}
+
+fn highlight_style() -> Style {
+    Style::default().add_modifier(Modifier::REVERSED)
+}
+
+fn block(title: &str) -> Block<'static> {
+    Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(format!(" {title} "), title_style()))
+}
+
+/// Header row with an arrow on the sorted column
+fn sorted_header<'a>(columns: &[&'a str], sort: Sort) -> Row<'a> {
+    Row::new(columns.iter().enumerate().map(|(idx, name)| {
+        let arrow = match (idx == sort.column, sort.descending) {
+            (false, _) => "",
+            (true, true) => " ▼",
+            (true, false) => " ▲",
+        };
+        Cell::from(format!("{name}{arrow}"))
+    }))
+    .style(header_style())
+}
+
+fn right(value: &dyn std::fmt::Display) -> Cell<'static> {
+    let text = value.to_string();
+    Cell::from(Line::from(text).alignment(ratatui::layout::Alignment::Right))
+}
+
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(frame.size());
+
+    let titles: Vec<Line> = Tab::ALL
+        .iter()
+        .enumerate()
+        .map(|(idx, tab)| Line::from(format!("{} {}", idx + 1, tab.title())))
+        .collect();
+    let selected = Tab::ALL.iter().position(|tab| *tab == app.tab).unwrap_or(0);
+    let tabs = Tabs::new(titles)
+        .block(block("gtct"))
+        .select(selected)
+        .highlight_style(title_style().add_modifier(Modifier::UNDERLINED));
+    frame.render_widget(tabs, chunks[0]);
+
+    if let Mode::Drill(key) = app.mode.clone() {
+        draw_drill(frame, app, &key, chunks[1]);
+    } else {
+        match app.tab {
+            Tab::Summary => draw_summary(frame, app, chunks[1]),
+            Tab::Contributors => draw_contributors(frame, app, chunks[1]),
+            Tab::Time => draw_time(frame, app, chunks[1]),
+            Tab::Files => draw_files(frame, app, chunks[1]),
+            Tab::Trends => draw_trends(frame, app, chunks[1]),
+        }
+    }
+
+    draw_footer(frame, app, chunks[2]);
+}
+
+/// Current filter on the left of the footer
+fn filter_label(app: &App) -> String {
+    let days = if app.filter.days == 0 {
+        "all time".to_string()
+    } else {
+        format!("last {} days", app.filter.days)
+    };
+    let team = app.filter.team.as_deref().unwrap_or("all teams");
+    let path = if app.filter.path.is_empty() {
+        "/"
+    } else {
+        app.filter.path.as_str()
+    };
+    let interval = match app.filter.interval {
+        Interval::Week => "weekly",
+        Interval::Month => "monthly",
+    };
+    format!("{days} · {team} · {path} · {interval}")
+}
+
+fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let line = match &app.mode {
+        Mode::PathInput(buffer) => Line::from(vec![
+            Span::styled("Path prefix: ", header_style()),
+            Span::raw(buffer.clone()),
+            Span::styled("█", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            Span::styled("  (Enter apply, Esc cancel)", Style::default().fg(Color::DarkGray)),
+        ]),
+        Mode::Drill(_) => Line::from(vec![
+            Span::styled(filter_label(app), header_style()),
+            Span::styled("  ↑↓ move  Esc back  q quit", Style::default().fg(Color::DarkGray)),
+        ]),
+        Mode::Browse => Line::from(vec![
+            Span::styled(filter_label(app), header_style()),
+            Span::styled(
+                "  ←→ tab  ↑↓ move  s sort  r reverse  Enter drill  d/D days  t team  i interval  / path  c clear  q quit",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+    };
+    frame.render_widget(Paragraph::new(line).block(Block::default().borders(Borders::ALL)), area);
+}
+
+fn draw_summary(frame: &mut Frame, app: &App, area: Rect) {
+    let data = &app.data;
+    let commits: usize = data.contributors.iter().map(|s| s.commits).sum();
+    let additions: usize = data.contributors.iter().map(|s| s.additions).sum();
+    let deletions: usize = data.contributors.iter().map(|s| s.deletions).sum();
+    let active_days: usize = data.contributors.iter().map(|s| s.active_days).sum();
+
+    let time = &data.time;
+    let busiest = |counts: &HashMap<u32, usize>| {
+        counts
+            .iter()
+            .max_by_key(|(key, count)| (**count, Reverse(**key)))
+            .map_or(0, |(key, _)| *key)
+    };
+    let busiest_hour = busiest(&time.hour_distribution);
+    let busiest_day = busiest(&time.day_distribution) as usize;
+
+    let stat = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{label:<16}"), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(value),
+        ])
+    };
+    let mut lines = vec![
+        stat("Commits", commits.to_string()),
+        stat("Contributors", data.contributors.len().to_string()),
+        stat("Lines", format!("+{additions} / -{deletions}")),
+        stat("Files Touched", data.files.len().to_string()),
+        stat("Active Days", format!("{active_days} person-days")),
+    ];
+    if commits > 0 {
+        lines.push(stat("Busiest Hour", format!("{busiest_hour:02}:00")));
+        lines.push(stat("Busiest Day", WEEKDAYS[busiest_day].to_string()));
+    }
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled("Top Contributors", header_style())));
+
+    let mut top: Vec<&ContributorStats> = data.contributors.iter().collect();
+    top.sort_by_key(|s| std::cmp::Reverse(s.commits));
+    let max = top.first().map_or(1, |s| s.commits.max(1));
+    for stat in top.iter().take(10) {
+        let bar = "█".repeat((stat.commits * 30 + max - 1) / max);
+        lines.push(Line::from(vec![
+            Span::raw(format!("{:<20} │ ", truncate(&stat.name, 20))),
+            Span::styled(bar, Style::default().fg(Color::Green)),
+            Span::raw(format!(" ({})", stat.commits)),
+        ]));
+    }
+    if top.is_empty() {
+        lines.push(Line::from(Span::styled("None", Style::default().fg(Color::DarkGray))));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block("Summary")), area);
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        text.to_string()
+    } else {
+        let mut cut: String = text.chars().take(width.saturating_sub(1)).collect();
+        cut.push('…');
+        cut
+    }
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn draw_contributors(frame: &mut Frame, app: &mut App, area: Rect) {
+    let rows = app.data.contributors.iter().map(|stat| {
+        let net = stat.additions as i64 - stat.deletions as i64;
+        Row::new(vec![
+            Cell::from(stat.name.clone()),
+            right(&stat.commits),
+            right(&format!("+{}", stat.additions)),
+            right(&format!("-{}", stat.deletions)),
+            right(&stat.files_changed),
+            right(&format!("{net:+}")),
+            right(&stat.active_days),
+            right(&format_date(stat.last_commit)),
+        ])
+    });
+    let widths = [
+        Constraint::Min(20),
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(10),
+        Constraint::Length(13),
+        Constraint::Length(13),
+    ];
+    let table = Table::new(rows, widths)
+        .header(sorted_header(&CONTRIBUTOR_COLUMNS, app.contributor_sort))
+        .block(block("Contributors (Enter for details)"))
+        .highlight_style(highlight_style());
+    frame.render_stateful_widget(table, area, &mut app.contributors);
+}
+
+fn file_rows(files: &[FileStats]) -> impl Iterator<Item = Row<'_>> {
+    files.iter().map(|file| {
+        Row::new(vec![
+            Cell::from(file.path.clone()),
+            right(&file.changes),
+            right(&format!("+{}", file.additions)),
+            right(&format!("-{}", file.deletions)),
+            right(&file.churn()),
+            right(&file.contributors.len()),
+        ])
+    })
+}
+
+const FILE_WIDTHS: [Constraint; 6] = [
+    Constraint::Min(30),
+    Constraint::Length(10),
+    Constraint::Length(10),
+    Constraint::Length(10),
+    Constraint::Length(10),
+    Constraint::Length(14),
+];
+
+fn draw_files(frame: &mut Frame, app: &mut App, area: Rect) {
+    let table = Table::new(file_rows(&app.data.files), FILE_WIDTHS)
+        .header(sorted_header(&FILE_COLUMNS, app.file_sort))
+        .block(block("Files (Enter to filter by path)"))
+        .highlight_style(highlight_style());
+    frame.render_stateful_widget(table, area, &mut app.files);
+}
+
+fn draw_time(frame: &mut Frame, app: &App, area: Rect) {
+    let heatmap = &app.data.time.weekday_hours;
+    let max = heatmap.iter().flatten().copied().max().unwrap_or(0).max(1);
+
+    let header: String = std::iter::once("     ".to_string())
+        .chain((0..24).step_by(3).map(|hour| format!("{hour:<6}")))
+        .collect();
+    let mut lines = vec![Line::from(Span::styled(header, header_style()))];
+    for (day, hours) in heatmap.iter().enumerate() {
+        let mut spans = vec![Span::styled(
+            format!("{:<5}", WEEKDAYS[day]),
+            header_style(),
+        )];
+        for count in hours {
+            let shade = if *count == 0 {
+                0
+            } else {
+                (count * (SHADES.len() - 1) + max - 1) / max
+            };
+            spans.push(Span::styled(SHADES[shade], Style::default().fg(Color::Green)));
+        }
+        spans.push(Span::raw(format!("  {}", hours.iter().sum::<usize>())));
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::default());
+    let legend: Vec<Span> = std::iter::once(Span::raw("Less "))
+        .chain(
+            SHADES
+                .iter()
+                .map(|shade| Span::styled(*shade, Style::default().fg(Color::Green))),
+        )
+        .chain(std::iter::once(Span::raw(format!(" More (max {max} commits/hour)"))))
+        .collect();
+    lines.push(Line::from(legend));
+
+    frame.render_widget(
+        Paragraph::new(lines).block(block("Commits by Weekday and Hour (local time)")),
+        area,
+    );
+}
+
+fn draw_trends(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    // Show as many of the latest periods as fit, each bar as wide as its label
+    let fits = usize::from(chunks[0].width.saturating_sub(2) / 9).max(1);
+    let trends = &app.data.trends[app.data.trends.len().saturating_sub(fits)..];
+    let bars: Vec<(&str, u64)> =
+        trends.iter().map(|t| (t.label.as_str(), t.commits as u64)).collect();
+    let chart = BarChart::default()
+        .block(block("Commits per Period"))
+        .data(bars.as_slice())
+        .bar_width(8)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(Color::Green))
+        .value_style(Style::default().fg(Color::Black).bg(Color::Green));
+    frame.render_widget(chart, chunks[0]);
+
+    let rows = app.data.trends.iter().rev().map(|trend| {
+        Row::new(vec![
+            Cell::from(trend.label.clone()),
+            right(&trend.commits),
+            right(&trend.churn),
+            right(&trend.authors),
+        ])
+    });
+    let widths = [
+        Constraint::Length(12),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(10),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(["Period", "Commits", "Churn", "Authors"]).style(header_style()))
+        .block(block("Periods (newest first)"));
+    frame.render_widget(table, chunks[1]);
+}
+
+fn draw_drill(frame: &mut Frame, app: &mut App, key: &str, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let name = key.split('|').next().unwrap_or(key);
+
+    let files = app.data.files_by_author.get(key).map_or(&[][..], Vec::as_slice);
+    let table = Table::new(file_rows(files), FILE_WIDTHS)
+        .header(Row::new(FILE_COLUMNS).style(header_style()))
+        .block(block(&format!("{name}: Files")));
+    frame.render_widget(table, chunks[0]);
+
+    let commits = app.data.commits_by_author.get(key).map_or(&[][..], Vec::as_slice);
+    let rows = commits.iter().map(|commit| {
+        Row::new(vec![
+            Cell::from(commit.hash.clone()),
+            Cell::from(commit.date.format("%Y-%m-%d %H:%M").to_string()),
+            right(&format!("+{}", commit.additions)),
+            right(&format!("-{}", commit.deletions)),
+            Cell::from(commit.summary.clone()),
+        ])
+    });
+    let widths = [
+        Constraint::Length(8),
+        Constraint::Length(17),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Min(20),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(["Commit", "Date", "Added", "Deleted", "Summary"]).style(header_style()))
+        .block(block(&format!("{name}: Commits")))
+        .highlight_style(highlight_style());
+    frame.render_stateful_widget(table, chunks[1], &mut app.commits);
+}